@@ -1,6 +1,8 @@
-use crate::position::Position;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
 
-#[derive(Clone, Copy)]
+use crate::{position::Position, tables};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct Bitboard {
     data: u64,
 }
@@ -20,11 +22,11 @@ impl Bitboard {
         self.data & (1 << position.index()) != 0
     }
 
-    pub fn set(&mut self, position: Position) {
+    pub const fn set(&mut self, position: Position) {
         self.data |= 1 << (position.index());
     }
 
-    pub fn remove(&mut self, position: Position) {
+    pub const fn remove(&mut self, position: Position) {
         self.data &= !(1 << (position.index()));
     }
 
@@ -32,7 +34,118 @@ impl Bitboard {
         Self { data }
     }
 
+    pub const fn from_position(position: Position) -> Self {
+        Self {
+            data: 1 << position.index(),
+        }
+    }
+
     pub const fn data(self) -> u64 {
         self.data
     }
+
+    pub const fn is_empty(self) -> bool {
+        self.data == 0
+    }
+
+    pub const fn count(self) -> u32 {
+        self.data.count_ones()
+    }
+
+    /// The squares strictly between `a` and `b`, or nothing if they are not aligned.
+    pub const fn between(a: Position, b: Position) -> Self {
+        Self::from_data(tables::BETWEEN[a.index()][b.index()])
+    }
+
+    /// The whole rank, file or diagonal through `a` and `b`, or nothing if they are not aligned.
+    pub const fn line(a: Position, b: Position) -> Self {
+        Self::from_data(tables::LINE[a.index()][b.index()])
+    }
+
+    pub fn first(self) -> Option<Position> {
+        if self.is_empty() {
+            return None;
+        }
+        Position::try_from(self.data.trailing_zeros() as usize).ok()
+    }
+}
+
+impl From<Position> for Bitboard {
+    fn from(position: Position) -> Self {
+        Self::from_position(position)
+    }
+}
+
+impl BitAnd for Bitboard {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self::from_data(self.data & rhs.data)
+    }
+}
+
+impl BitOr for Bitboard {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self::from_data(self.data | rhs.data)
+    }
+}
+
+impl BitXor for Bitboard {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self {
+        Self::from_data(self.data ^ rhs.data)
+    }
+}
+
+impl Not for Bitboard {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self::from_data(!self.data)
+    }
+}
+
+impl BitAndAssign for Bitboard {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.data &= rhs.data;
+    }
+}
+
+impl BitOrAssign for Bitboard {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.data |= rhs.data;
+    }
+}
+
+impl BitXorAssign for Bitboard {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.data ^= rhs.data;
+    }
+}
+
+impl IntoIterator for Bitboard {
+    type Item = Position;
+    type IntoIter = BitboardIter;
+    fn into_iter(self) -> BitboardIter {
+        BitboardIter { remaining: self }
+    }
+}
+
+pub struct BitboardIter {
+    remaining: Bitboard,
+}
+
+impl Iterator for BitboardIter {
+    type Item = Position;
+    fn next(&mut self) -> Option<Position> {
+        let position = self.remaining.first()?;
+        self.remaining.remove(position);
+        Some(position)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.remaining.count() as usize;
+        (count, Some(count))
+    }
 }
+
+impl ExactSizeIterator for BitboardIter {}
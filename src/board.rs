@@ -2,18 +2,31 @@ use std::fmt::Display;
 
 use enum_map::{enum_map, Enum, EnumMap};
 use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
 
 use crate::{
     bitboard::Bitboard,
-    errors::{InvalidChar, InvalidFenString, InvalidMove},
-    half_move::HalfMove,
+    errors::{InvalidChar, InvalidMove},
+    half_move::{HalfMove, MoveKind},
     piece::{Color, Piece},
     piece_map::PieceMap,
     pieces,
     position::{self, Axis, Position},
+    zobrist,
 };
 
-#[derive(Clone, Copy)]
+mod fen;
+mod movegen;
+mod outcome;
+pub mod perft;
+mod san;
+mod uci;
+
+pub use outcome::{DrawReason, Outcome};
+
+pub const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+#[derive(Clone)]
 pub struct Board {
     data: PieceMap<Bitboard>,
     castling_rights: CastlingRights,
@@ -21,8 +34,26 @@ pub struct Board {
     half_move_clock: u32,
     current_turn: Color,
     en_passant_target: Option<Position>,
+    hash: u64,
+    /// Hashes of every position before the current one, oldest first.
+    history: Vec<u64>,
+}
+
+/// Boards are equal when they describe the same position and clocks, regardless of the moves
+/// that led there.
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+            && self.castling_rights == other.castling_rights
+            && self.full_move_clock == other.full_move_clock
+            && self.half_move_clock == other.half_move_clock
+            && self.current_turn == other.current_turn
+            && self.en_passant_target == other.en_passant_target
+    }
 }
 
+impl Eq for Board {}
+
 impl Default for Board {
     fn default() -> Self {
         Self::new()
@@ -34,18 +65,51 @@ impl Board {
         self.current_turn
     }
 
+    /// The Polyglot-compatible Zobrist hash of the current position.
+    pub const fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    pub const fn en_passant_target(&self) -> Option<Position> {
+        self.en_passant_target
+    }
+
+    pub fn castling_right(&self, color: Color, side: BoardSide) -> bool {
+        *self.castling_rights.get(color, side)
+    }
+
+    pub fn pieces(&self, piece: &Piece) -> Bitboard {
+        *self.data.get(piece)
+    }
+
+    pub fn occupied_by(&self, color: Color) -> Bitboard {
+        pieces::PIECE_TYPES
+            .iter()
+            .fold(Bitboard::new(), |occupied, ptype| {
+                occupied | self.pieces(&Piece::new(ptype, color))
+            })
+    }
+
+    pub fn occupied(&self) -> Bitboard {
+        self.occupied_by(Color::White) | self.occupied_by(Color::Black)
+    }
+
     pub fn empty() -> Self {
-        Self {
+        let mut board = Self {
             data: PieceMap::new(|_| Bitboard::new()),
             castling_rights: CastlingRights::new(),
             full_move_clock: 0,
             half_move_clock: 0,
             current_turn: Color::White,
             en_passant_target: None,
-        }
+            hash: 0,
+            history: Vec::new(),
+        };
+        board.hash = board.compute_hash();
+        board
     }
 
-    fn get(&self, position: Position) -> Option<Piece> {
+    pub fn get(&self, position: Position) -> Option<Piece> {
         for ptype in pieces::PIECE_TYPES {
             for color in Color::iter() {
                 if self.data.get(&Piece::new(ptype, color)).get(position) {
@@ -58,158 +122,232 @@ impl Board {
 
     fn set(&mut self, piece: &Piece, position: Position) {
         self.data.get_mut(piece).set(position);
+        self.hash ^= zobrist::piece(piece, position);
     }
 
-    fn remove(&mut self, position: Position) {
+    fn remove(&mut self, position: Position) -> Option<Piece> {
         for ptype in pieces::PIECE_TYPES {
             for color in Color::iter() {
-                let bitboard = self.data.get_mut(&Piece::new(ptype, color));
+                let piece = Piece::new(ptype, color);
+                let bitboard = self.data.get_mut(&piece);
                 if bitboard.get(position) {
                     bitboard.remove(position);
-                    return;
+                    self.hash ^= zobrist::piece(&piece, position);
+                    return Some(piece);
                 }
             }
         }
+        None
     }
 
+    /// Plays `half_move` if it is legal for the side to move, otherwise leaves the board untouched
+    /// and explains why the move was rejected.
     pub fn move_piece(&mut self, half_move: &HalfMove) -> Result<(), InvalidMove> {
-        let piece = self.get(*half_move.from()).ok_or_else(|| InvalidMove {
-            reason: "No piece at position 'from'".to_string(),
-        })?;
-        self.remove(*half_move.from());
-        self.remove(*half_move.to());
-        self.set(&piece, *half_move.to());
+        let legal_move = self.validate_move(half_move)?;
+        self.make_move(&legal_move);
         Ok(())
     }
 
-    pub fn new() -> Self {
-        Self::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
-            .expect("FEN string for starting board failed to be parsed")
-    }
+    /// Finds the legal move with the same squares and promotion as `half_move`, which may have
+    /// been built without knowing its [`MoveKind`].
+    pub fn validate_move(&self, half_move: &HalfMove) -> Result<HalfMove, InvalidMove> {
+        let invalid = |reason: &str| {
+            Err(InvalidMove {
+                reason: reason.to_string(),
+            })
+        };
 
-    fn parse_data_fen_field(string: &str) -> Result<PieceMap<Bitboard>, InvalidFenString> {
-        let rank_iter = Axis::iter().rev();
-        let mut board = PieceMap::new(|_| Bitboard::new());
-        let lines = string.split('/');
-        for (line, rank) in lines.zip(rank_iter) {
-            Self::parse_line_in_fen_data_field(line, rank, &mut board)?;
+        let Some(piece) = self.get(*half_move.from()) else {
+            return invalid("No piece at position 'from'");
+        };
+        if piece.color() != self.current_turn {
+            return invalid("Piece at position 'from' belongs to the player not on turn");
         }
-        Ok(board)
-    }
-
-    fn parse_line_in_fen_data_field(
-        line: &str,
-        rank: Axis,
-        board: &mut PieceMap<Bitboard>,
-    ) -> Result<(), InvalidFenString> {
-        let mut file_iter = Axis::iter();
-        let mut file = file_iter.next();
-        for character in line.chars() {
-            if let Some(digit) = character.to_digit(10) {
-                for _ in 0..digit {
-                    file = file_iter.next();
-                }
-            } else {
-                if let Some(file) = file {
-                    let position = Position::new(rank, file);
-                    let piece = &Piece::try_from(character).map_err(|_| InvalidFenString {})?;
-                    board.get_mut(piece).set(position);
+        if let Some(legal_move) = self
+            .legal_moves()
+            .iter()
+            .find(|legal_move| legal_move.same_squares(half_move))
+        {
+            return Ok(*legal_move);
+        }
+
+        let pseudo_legal = piece.get_pseudo_legal_moves(self, *half_move.from());
+        let same_squares = |candidate: &&HalfMove| {
+            candidate.from() == half_move.from() && candidate.to() == half_move.to()
+        };
+        match pseudo_legal.iter().find(same_squares) {
+            None => invalid("Piece cannot move to position 'to'"),
+            Some(candidate) if candidate.promotion() != half_move.promotion() => {
+                if half_move.promotion().is_none() {
+                    invalid("Pawn reaching the last rank must name a promotion piece")
+                } else {
+                    invalid("Promotion piece given for a move that does not promote")
                 }
-                file = file_iter.next();
-            };
+            }
+            Some(_) => invalid("Move would leave the king in check"),
         }
-        Ok(())
     }
 
-    fn parse_current_turn_fen_field(string: &str) -> Result<Color, InvalidFenString> {
-        let character = (string.chars().next().ok_or(InvalidFenString {}))?;
-        Color::try_from(character).map_err(|_| InvalidFenString {})
-    }
+    /// Applies a legal move, as produced by [`Board::legal_moves`], updating castling rights, the
+    /// en passant target, both clocks and the side to move. The returned [`UndoInfo`] lets
+    /// [`Board::unmake_move`] restore the position exactly.
+    pub fn make_move(&mut self, half_move: &HalfMove) -> UndoInfo {
+        let (from, to) = (*half_move.from(), *half_move.to());
+        let color = self.current_turn;
+        let mut undo = UndoInfo {
+            captured: None,
+            castling_rights: self.castling_rights,
+            en_passant_target: self.en_passant_target,
+            half_move_clock: self.half_move_clock,
+            full_move_clock: self.full_move_clock,
+            hash: self.hash,
+        };
+        self.history.push(self.hash);
+        self.hash ^= self.castling_hash() ^ self.en_passant_hash();
+        let piece = self
+            .remove(from)
+            .expect("legal move must start on an occupied square");
+        let is_pawn = *piece.ptype() == pieces::PAWN;
+
+        let captured = if half_move.kind() == MoveKind::EnPassant {
+            self.remove(Position::new(*from.rank(), *to.file()))
+        } else {
+            self.remove(to)
+        };
+        let placed = half_move
+            .promotion()
+            .map_or(piece, |ptype| Piece::new(ptype, color));
+        self.set(&placed, to);
+
+        if let MoveKind::Castle(side) = half_move.kind() {
+            let rook_from = Position::new(*from.rank(), side.rook_file());
+            let rook_to = Position::new(*from.rank(), side.rook_destination_file());
+            if let Some(rook) = self.remove(rook_from) {
+                self.set(&rook, rook_to);
+            }
+        }
+        if *piece.ptype() == pieces::KING {
+            for side in BoardSide::iter() {
+                self.castling_rights.remove(color, side);
+            }
+        }
+        for color in Color::iter() {
+            for side in BoardSide::iter() {
+                let corner = Position::new(color.back_rank(), side.rook_file());
+                if from == corner || to == corner {
+                    self.castling_rights.remove(color, side);
+                }
+            }
+        }
 
-    fn parse_castling_rights_fen_field(string: &str) -> Result<CastlingRights, InvalidFenString> {
-        let mut out = CastlingRights::new();
-        for character in string.chars() {
-            let colour = match character {
-                '-' => return Ok(out),
-                c if c.is_lowercase() => Color::White,
-                c if c.is_uppercase() => Color::Black,
-                _ => return Err(InvalidFenString {}),
-            };
+        self.en_passant_target = None;
+        if half_move.kind() == MoveKind::DoublePawnPush {
+            self.en_passant_target = Position::try_from(from.index().midpoint(to.index())).ok();
+        }
 
-            let board_side = BoardSide::try_from(character).map_err(|_| InvalidFenString {})?;
-            out.data[colour][board_side] = true;
+        if is_pawn || captured.is_some() {
+            self.half_move_clock = 0;
+        } else {
+            self.half_move_clock += 1;
+        }
+        if color == Color::Black {
+            self.full_move_clock += 1;
         }
-        Ok(out)
+        self.current_turn = color.opposite();
+        self.hash ^= self.castling_hash() ^ self.en_passant_hash() ^ zobrist::white_to_move();
+        debug_assert_eq!(
+            self.hash,
+            self.compute_hash(),
+            "incremental hash diverged from recomputation"
+        );
+
+        undo.captured = captured;
+        undo
     }
 
-    fn parse_en_passant_fen_field(string: &str) -> Result<Option<Position>, InvalidFenString> {
-        let mut characters = string.chars();
-
-        let first_character = characters.next().ok_or(InvalidFenString {})?;
-        if first_character == '-' {
-            return Ok(None);
+    /// Takes back `half_move`, which must be the last move made with [`Board::make_move`].
+    pub fn unmake_move(&mut self, half_move: &HalfMove, undo: &UndoInfo) {
+        let (from, to) = (*half_move.from(), *half_move.to());
+        let color = self.current_turn.opposite();
+        let placed = self
+            .remove(to)
+            .expect("unmade move must end on an occupied square");
+        let piece = if half_move.promotion().is_some() {
+            Piece::new(&pieces::PAWN, color)
+        } else {
+            placed
         };
-        let second_character = characters.next().ok_or(InvalidFenString {})?;
+        self.set(&piece, from);
 
-        if characters.next().is_some() {
-            return Err(InvalidFenString {});
+        if let MoveKind::Castle(side) = half_move.kind() {
+            let rook_from = Position::new(*from.rank(), side.rook_file());
+            let rook_to = Position::new(*from.rank(), side.rook_destination_file());
+            if let Some(rook) = self.remove(rook_to) {
+                self.set(&rook, rook_from);
+            }
         }
 
-        Ok(Some(
-            Position::try_from([first_character, second_character])
-                .map_err(|_| InvalidFenString {})?,
-        ))
-    }
-
-    fn parse_move_clock_fen_field(string: &str) -> Result<u32, InvalidFenString> {
-        string.parse::<u32>().map_err(|_| InvalidFenString {})
-    }
-
-    pub fn from_fen(fen_string: &str) -> Result<Self, InvalidFenString> {
-        let fen_fields: Vec<&str> = fen_string.split(' ').collect();
-        Ok(Self {
-            data: Self::parse_data_fen_field(fen_fields[0])?,
-            current_turn: Self::parse_current_turn_fen_field(fen_fields[1])?,
-            castling_rights: Self::parse_castling_rights_fen_field(fen_fields[2])?,
-            en_passant_target: Self::parse_en_passant_fen_field(fen_fields[3])?,
-            half_move_clock: Self::parse_move_clock_fen_field(fen_fields[4])?,
-            full_move_clock: Self::parse_move_clock_fen_field(fen_fields[5])?,
-        })
-    }
-
-    fn parse_char_in_first_fen_field(
-        character: char,
-        rank: &mut Option<position::Axis>,
-        rank_iter: &mut position::AxisIter,
-        file_iter: &mut position::AxisIter,
-        file: &mut Option<position::Axis>,
-        board: &mut Self,
-    ) -> Result<(), InvalidFenString> {
-        if character == '/' {
-            *rank = rank_iter.next();
-            *file_iter = position::Axis::iter();
-            *file = file_iter.next();
-        } else if let Some(digit) = character.to_digit(10) {
-            for _ in 0..digit {
-                *file = file_iter.next();
-            }
+        if let Some(captured) = undo.captured {
+            let square = if half_move.kind() == MoveKind::EnPassant {
+                Position::new(*from.rank(), *to.file())
+            } else {
+                to
+            };
+            self.set(&captured, square);
+        }
+
+        self.castling_rights = undo.castling_rights;
+        self.en_passant_target = undo.en_passant_target;
+        self.half_move_clock = undo.half_move_clock;
+        self.full_move_clock = undo.full_move_clock;
+        self.current_turn = color;
+        self.hash = undo.hash;
+        self.history.pop();
+    }
+
+    fn compute_hash(&self) -> u64 {
+        let pieces = pieces::PIECE_TYPES
+            .iter()
+            .flat_map(|ptype| Color::iter().map(|color| Piece::new(ptype, color)))
+            .flat_map(|piece| {
+                self.pieces(&piece)
+                    .into_iter()
+                    .map(move |position| zobrist::piece(&piece, position))
+            })
+            .fold(0, |hash, key| hash ^ key);
+        let turn = if self.current_turn == Color::White {
+            zobrist::white_to_move()
         } else {
-            if let Some(rank) = *rank {
-                if let Some(file) = *file {
-                    board.set(
-                        &Piece::try_from(character).map_err(|_| InvalidFenString {})?,
-                        Position::new(rank, file),
-                    );
-                }
-            }
-            *file = file_iter.next();
+            0
         };
-        Ok(())
+        pieces ^ self.castling_hash() ^ self.en_passant_hash() ^ turn
     }
 
-    pub const fn get_outcome(&self) -> Option<Outcome> {
-        _ = self;
-        None
+    fn castling_hash(&self) -> u64 {
+        Color::iter()
+            .flat_map(|color| BoardSide::iter().map(move |side| (color, side)))
+            .filter(|&(color, side)| *self.castling_rights.get(color, side))
+            .fold(0, |hash, (color, side)| {
+                hash ^ zobrist::castling(color, side)
+            })
+    }
+
+    /// Like Polyglot, the en passant file only counts when a pawn could actually capture there.
+    fn en_passant_hash(&self) -> u64 {
+        let Some(target) = self.en_passant_target else {
+            return 0;
+        };
+        let pawns = self.pieces(&Piece::new(&pieces::PAWN, self.current_turn));
+        let attackers = pieces::pawn::attacks(self.current_turn.opposite(), target) & pawns;
+        if attackers.is_empty() {
+            0
+        } else {
+            zobrist::en_passant(*target.file())
+        }
+    }
+
+    pub fn new() -> Self {
+        Self::from_fen(START_FEN).expect("FEN string for starting board failed to be parsed")
     }
 }
 
@@ -238,12 +376,35 @@ impl Board {
     }
 }
 
-#[derive(Enum, Clone, Copy)]
-enum BoardSide {
+#[derive(Enum, Clone, Copy, EnumIter, PartialEq, Eq, Debug)]
+pub enum BoardSide {
     QueenSide,
     KingSide,
 }
 
+impl BoardSide {
+    pub const fn rook_file(self) -> Axis {
+        match self {
+            Self::QueenSide => Axis::A,
+            Self::KingSide => Axis::H,
+        }
+    }
+
+    pub const fn king_destination_file(self) -> Axis {
+        match self {
+            Self::QueenSide => Axis::C,
+            Self::KingSide => Axis::G,
+        }
+    }
+
+    pub const fn rook_destination_file(self) -> Axis {
+        match self {
+            Self::QueenSide => Axis::D,
+            Self::KingSide => Axis::F,
+        }
+    }
+}
+
 impl TryFrom<char> for BoardSide {
     type Error = InvalidChar;
     fn try_from(character: char) -> Result<Self, Self::Error> {
@@ -255,7 +416,18 @@ impl TryFrom<char> for BoardSide {
     }
 }
 
+/// Everything [`Board::make_move`] overwrites that cannot be recovered from the move itself.
 #[derive(Clone, Copy)]
+pub struct UndoInfo {
+    captured: Option<Piece>,
+    castling_rights: CastlingRights,
+    en_passant_target: Option<Position>,
+    half_move_clock: u32,
+    full_move_clock: u32,
+    hash: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
 struct CastlingRights {
     data: EnumMap<Color, EnumMap<BoardSide, bool>>,
 }
@@ -267,6 +439,12 @@ impl CastlingRights {
         }
     }
 
+    pub fn none() -> Self {
+        Self {
+            data: enum_map! { _ => enum_map! { _ => false} },
+        }
+    }
+
     pub fn get(&self, colour: Color, side: BoardSide) -> &bool {
         &self.data[colour][side]
     }
@@ -276,7 +454,47 @@ impl CastlingRights {
     }
 }
 
-pub enum Outcome {
-    Checkmate(Color),
-    Stalemate,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::magic::Xorshift;
+
+    const GAMES_PER_POSITION: usize = 10;
+    const MAX_PLIES: usize = 60;
+
+    /// Plays random games from every perft position, and checks at each position reached that
+    /// making and unmaking every legal move restores the board and its hash.
+    #[test]
+    fn unmake_move_restores_the_position() {
+        let mut rng = Xorshift::new(0x9E37_79B9_7F4A_7C15);
+        let mut positions = 0;
+        for (name, fen, _) in perft::SUITE {
+            for _ in 0..GAMES_PER_POSITION {
+                let mut board = Board::from_fen(fen).expect("suite FEN strings are valid");
+                for _ in 0..MAX_PLIES {
+                    let moves = board.legal_moves();
+                    for half_move in &moves {
+                        let before = board.clone();
+                        let undo = board.make_move(half_move);
+                        board.unmake_move(half_move, &undo);
+                        assert!(
+                            board == before,
+                            "{name}: unmaking {half_move} changed the board"
+                        );
+                        assert_eq!(
+                            board.hash(),
+                            before.hash(),
+                            "{name}: hash after {half_move}"
+                        );
+                    }
+                    let Some(half_move) = rng.choose(&moves) else {
+                        break;
+                    };
+                    board.make_move(half_move);
+                    positions += 1;
+                }
+            }
+        }
+        assert!(positions > 1000, "only {positions} positions were checked");
+    }
 }
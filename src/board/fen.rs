@@ -0,0 +1,464 @@
+use strum::IntoEnumIterator;
+
+use crate::{
+    bitboard::Bitboard,
+    errors::{FenField, InvalidFenString},
+    piece::{Color, Piece},
+    piece_map::PieceMap,
+    pieces::{KING, PAWN, ROOK},
+    position::{Axis, Position},
+};
+
+use super::{Board, BoardSide, CastlingRights};
+
+const CASTLING_CHARS: [(Color, BoardSide, char); 4] = [
+    (Color::White, BoardSide::KingSide, 'K'),
+    (Color::White, BoardSide::QueenSide, 'Q'),
+    (Color::Black, BoardSide::KingSide, 'k'),
+    (Color::Black, BoardSide::QueenSide, 'q'),
+];
+
+fn invalid<T>(field: FenField, reason: impl Into<String>) -> Result<T, InvalidFenString> {
+    Err(InvalidFenString {
+        field,
+        reason: reason.into(),
+    })
+}
+
+const fn rank_number(rank: Axis) -> usize {
+    rank as usize + 1
+}
+
+impl Board {
+    /// Parses a FEN string. The halfmove clock and fullmove number may be left off, as they are in
+    /// EPD records, in which case they default to 0 and 1.
+    pub fn from_fen(fen_string: &str) -> Result<Self, InvalidFenString> {
+        let fen_fields: Vec<&str> = fen_string.split_whitespace().collect();
+        let required = |index: usize, field: FenField| {
+            fen_fields
+                .get(index)
+                .copied()
+                .map_or_else(|| invalid(field, "field is missing"), Ok)
+        };
+        if fen_fields.len() > 6 {
+            return invalid(
+                FenField::FullMoveClock,
+                format!("unexpected text after the last field: '{}'", fen_fields[6]),
+            );
+        }
+
+        let mut board = Self {
+            data: Self::parse_data_fen_field(required(0, FenField::PiecePlacement)?)?,
+            current_turn: Self::parse_current_turn_fen_field(required(1, FenField::ActiveColor)?)?,
+            castling_rights: Self::parse_castling_rights_fen_field(required(
+                2,
+                FenField::CastlingRights,
+            )?)?,
+            en_passant_target: Self::parse_en_passant_fen_field(required(
+                3,
+                FenField::EnPassantTarget,
+            )?)?,
+            half_move_clock: fen_fields.get(4).map_or(Ok(0), |field| {
+                Self::parse_move_clock_fen_field(field, FenField::HalfMoveClock)
+            })?,
+            full_move_clock: fen_fields.get(5).map_or(Ok(1), |field| {
+                Self::parse_move_clock_fen_field(field, FenField::FullMoveClock)
+            })?,
+            hash: 0,
+            history: Vec::new(),
+        };
+        board.validate_fen_position()?;
+        board.hash = board.compute_hash();
+        Ok(board)
+    }
+
+    pub fn to_fen(&self) -> String {
+        let placement: String = Axis::iter()
+            .rev()
+            .map(|rank| self.rank_to_fen(rank))
+            .intersperse("/".to_string())
+            .collect();
+
+        let mut castling: String = CASTLING_CHARS
+            .iter()
+            .filter(|(color, side, _)| *self.castling_rights.get(*color, *side))
+            .map(|(_, _, character)| character)
+            .collect();
+        if castling.is_empty() {
+            castling.push('-');
+        }
+
+        let en_passant = self
+            .en_passant_target
+            .map_or_else(|| "-".to_string(), |target| target.to_string());
+
+        format!(
+            "{placement} {} {castling} {en_passant} {} {}",
+            char::from(self.current_turn),
+            self.half_move_clock,
+            self.full_move_clock
+        )
+    }
+
+    fn rank_to_fen(&self, rank: Axis) -> String {
+        let mut out = String::new();
+        let mut empty = 0;
+        for file in Axis::iter() {
+            match self.get(Position::new(rank, file)) {
+                Some(piece) => {
+                    if empty > 0 {
+                        out.push_str(&empty.to_string());
+                        empty = 0;
+                    }
+                    out.push((&piece).into());
+                }
+                None => empty += 1,
+            }
+        }
+        if empty > 0 {
+            out.push_str(&empty.to_string());
+        }
+        out
+    }
+
+    fn parse_data_fen_field(string: &str) -> Result<PieceMap<Bitboard>, InvalidFenString> {
+        let lines: Vec<&str> = string.split('/').collect();
+        if lines.len() != 8 {
+            return invalid(
+                FenField::PiecePlacement,
+                format!("expected 8 ranks but found {}", lines.len()),
+            );
+        }
+
+        let mut board = PieceMap::new(|_| Bitboard::new());
+        for (line, rank) in lines.into_iter().zip(Axis::iter().rev()) {
+            Self::parse_line_in_fen_data_field(line, rank, &mut board)?;
+        }
+        Ok(board)
+    }
+
+    fn parse_line_in_fen_data_field(
+        line: &str,
+        rank: Axis,
+        board: &mut PieceMap<Bitboard>,
+    ) -> Result<(), InvalidFenString> {
+        let mut file = 0;
+        for character in line.chars() {
+            if let Some(digit) = character
+                .to_digit(10)
+                .filter(|digit| (1..=8).contains(digit))
+            {
+                file += digit as usize;
+            } else {
+                let Ok(piece) = Piece::try_from(character) else {
+                    return invalid(
+                        FenField::PiecePlacement,
+                        format!(
+                            "unexpected character '{character}' in rank {}",
+                            rank_number(rank)
+                        ),
+                    );
+                };
+                if let Some(file) = Axis::from_repr(file) {
+                    board.get_mut(&piece).set(Position::new(rank, file));
+                }
+                file += 1;
+            }
+        }
+
+        if file != 8 {
+            return invalid(
+                FenField::PiecePlacement,
+                format!(
+                    "rank {} describes {file} squares instead of 8",
+                    rank_number(rank)
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    fn parse_current_turn_fen_field(string: &str) -> Result<Color, InvalidFenString> {
+        let mut characters = string.chars();
+        match (characters.next().map(Color::try_from), characters.next()) {
+            (Some(Ok(color)), None) => Ok(color),
+            _ => invalid(
+                FenField::ActiveColor,
+                format!("expected 'w' or 'b' but found '{string}'"),
+            ),
+        }
+    }
+
+    fn parse_castling_rights_fen_field(string: &str) -> Result<CastlingRights, InvalidFenString> {
+        let mut out = CastlingRights::none();
+        if string == "-" {
+            return Ok(out);
+        }
+
+        for character in string.chars() {
+            let Some(&(color, side, _)) = CASTLING_CHARS
+                .iter()
+                .find(|(_, _, castling_char)| *castling_char == character)
+            else {
+                return invalid(
+                    FenField::CastlingRights,
+                    format!("unexpected character '{character}'"),
+                );
+            };
+            if *out.get(color, side) {
+                return invalid(
+                    FenField::CastlingRights,
+                    format!("'{character}' appears more than once"),
+                );
+            }
+            out.data[color][side] = true;
+        }
+        Ok(out)
+    }
+
+    fn parse_en_passant_fen_field(string: &str) -> Result<Option<Position>, InvalidFenString> {
+        if string == "-" {
+            return Ok(None);
+        }
+
+        let characters: Vec<char> = string.chars().collect();
+        let square = <[char; 2]>::try_from(characters)
+            .ok()
+            .and_then(|characters| Position::try_from(characters).ok());
+        square.map_or_else(
+            || {
+                invalid(
+                    FenField::EnPassantTarget,
+                    format!("'{string}' is not '-' or a square name"),
+                )
+            },
+            |square| Ok(Some(square)),
+        )
+    }
+
+    fn parse_move_clock_fen_field(string: &str, field: FenField) -> Result<u32, InvalidFenString> {
+        string
+            .parse::<u32>()
+            .or_else(|_| invalid(field, format!("'{string}' is not a non-negative number")))
+    }
+
+    /// Rejects piece placements that cannot arise in a game: a missing or extra king, pawns on
+    /// the first or last rank, castling rights without the king and rook on their home squares,
+    /// or an en passant target no double pawn push could have left.
+    fn validate_fen_position(&self) -> Result<(), InvalidFenString> {
+        for color in Color::iter() {
+            let kings = self.pieces(&Piece::new(&KING, color)).count();
+            if kings != 1 {
+                return invalid(
+                    FenField::PiecePlacement,
+                    format!("{color:?} has {kings} kings instead of 1"),
+                );
+            }
+        }
+
+        let pawns = self.pieces(&Piece::new(&PAWN, Color::White))
+            | self.pieces(&Piece::new(&PAWN, Color::Black));
+        if let Some(pawn) = pawns
+            .into_iter()
+            .find(|pawn| matches!(pawn.rank(), Axis::A | Axis::H))
+        {
+            return invalid(
+                FenField::PiecePlacement,
+                format!("pawn on {pawn}, which is on the first or last rank"),
+            );
+        }
+
+        for &(color, side, character) in &CASTLING_CHARS {
+            let king_home = Position::new(color.back_rank(), Axis::E);
+            let rook_home = Position::new(color.back_rank(), side.rook_file());
+            if *self.castling_rights.get(color, side)
+                && (self.get(king_home) != Some(Piece::new(&KING, color))
+                    || self.get(rook_home) != Some(Piece::new(&ROOK, color)))
+            {
+                return invalid(
+                    FenField::CastlingRights,
+                    format!(
+                        "'{character}' needs the king on {king_home} and a rook on {rook_home}"
+                    ),
+                );
+            }
+        }
+
+        if let Some(target) = self.en_passant_target {
+            let mover = self.current_turn.opposite();
+            let forward = if mover == Color::White { 1 } else { -1 };
+            let expected_rank = if mover == Color::White {
+                Axis::C
+            } else {
+                Axis::F
+            };
+            let pushed_pawn = target.offset(forward, 0);
+            let origin = target.offset(-forward, 0);
+            let plausible = *target.rank() == expected_rank
+                && self.get(target).is_none()
+                && origin.is_some_and(|origin| self.get(origin).is_none())
+                && pushed_pawn.is_some_and(|pushed_pawn| {
+                    self.get(pushed_pawn) == Some(Piece::new(&PAWN, mover))
+                });
+            if !plausible {
+                return invalid(
+                    FenField::EnPassantTarget,
+                    format!("no {mover:?} pawn can have just pushed past {target}"),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{board::perft, magic::Xorshift};
+
+    fn error_field(fen: &str) -> FenField {
+        match Board::from_fen(fen) {
+            Ok(_) => panic!("'{fen}' parsed"),
+            Err(error) => error.field,
+        }
+    }
+
+    #[test]
+    fn to_fen_round_trips_random_positions() {
+        let mut rng = Xorshift::new(0x5DEE_CE66_D1CE_4E5B);
+        for (_, fen, _) in perft::SUITE {
+            for _ in 0..10 {
+                let mut board = Board::from_fen(fen).expect("suite FEN strings are valid");
+                for _ in 0..60 {
+                    let fen = board.to_fen();
+                    let parsed = Board::from_fen(&fen).expect("to_fen output parses");
+                    assert!(parsed == board, "{fen} did not round-trip");
+                    assert_eq!(parsed.hash(), board.hash(), "{fen} hashes differently");
+                    let Some(half_move) = rng.choose(&board.legal_moves()).copied() else {
+                        break;
+                    };
+                    board.make_move(&half_move);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clocks_default_when_left_off() {
+        let board = Board::from_fen("4k3/8/8/8/8/8/8/4K3 w - -").unwrap();
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+    }
+
+    #[test]
+    fn rejects_missing_fields() {
+        assert_eq!(error_field(""), FenField::PiecePlacement);
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4K3 w"),
+            FenField::CastlingRights
+        );
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4K3 w - -  0 1 extra"),
+            FenField::FullMoveClock
+        );
+    }
+
+    #[test]
+    fn rejects_bad_ranks() {
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/4K3 w - - 0 1"),
+            FenField::PiecePlacement
+        );
+        assert_eq!(
+            error_field("4k3/9/8/8/8/8/8/4K3 w - - 0 1"),
+            FenField::PiecePlacement
+        );
+        assert_eq!(
+            error_field("4k4/8/8/8/8/8/8/4K3 w - - 0 1"),
+            FenField::PiecePlacement
+        );
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4K2 w - - 0 1"),
+            FenField::PiecePlacement
+        );
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4X3 w - - 0 1"),
+            FenField::PiecePlacement
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_king_counts() {
+        assert_eq!(
+            error_field("8/8/8/8/8/8/8/4K3 w - - 0 1"),
+            FenField::PiecePlacement
+        );
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/3KK3 w - - 0 1"),
+            FenField::PiecePlacement
+        );
+    }
+
+    #[test]
+    fn rejects_back_rank_pawns() {
+        assert_eq!(
+            error_field("P3k3/8/8/8/8/8/8/4K3 w - - 0 1"),
+            FenField::PiecePlacement
+        );
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/p3K3 w - - 0 1"),
+            FenField::PiecePlacement
+        );
+    }
+
+    #[test]
+    fn rejects_bad_active_color_and_clocks() {
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4K3 x - - 0 1"),
+            FenField::ActiveColor
+        );
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4K3 w - - -1 1"),
+            FenField::HalfMoveClock
+        );
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4K3 w - - 0 one"),
+            FenField::FullMoveClock
+        );
+    }
+
+    #[test]
+    fn rejects_castling_rights_without_king_and_rook() {
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4K3 w KQkq - 0 1"),
+            FenField::CastlingRights
+        );
+        assert_eq!(
+            error_field("r3k2r/8/8/8/8/8/8/R2K3R w Kkq - 0 1"),
+            FenField::CastlingRights
+        );
+        assert_eq!(
+            error_field("r3k2r/8/8/8/8/8/8/R3K1R1 w Kkq - 0 1"),
+            FenField::CastlingRights
+        );
+        assert!(Board::from_fen("r3k2r/8/8/8/8/8/8/R3K1R1 w Qkq - 0 1").is_ok());
+    }
+
+    #[test]
+    fn rejects_implausible_en_passant_targets() {
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4K3 b - e9 0 1"),
+            FenField::EnPassantTarget
+        );
+        // No pawn stands in front of the target.
+        assert_eq!(
+            error_field("4k3/8/8/8/8/8/8/4K3 b - e3 0 1"),
+            FenField::EnPassantTarget
+        );
+        // The target is on the wrong rank for the side that just moved.
+        assert_eq!(
+            error_field("4k3/8/8/8/4P3/8/8/4K3 w - e3 0 1"),
+            FenField::EnPassantTarget
+        );
+        assert!(Board::from_fen("4k3/8/8/8/4P3/8/8/4K3 b - e3 0 1").is_ok());
+    }
+}
@@ -0,0 +1,156 @@
+use strum::IntoEnumIterator;
+
+use crate::{
+    bitboard::Bitboard,
+    half_move::{HalfMove, MoveKind},
+    magic,
+    piece::{Color, Piece},
+    pieces::{self, PieceType, BISHOP, KING, KNIGHT, PAWN, QUEEN, ROOK},
+    position::Position,
+};
+
+use super::Board;
+
+impl Board {
+    fn pieces_of(&self, ptype: &'static PieceType, color: Color) -> Bitboard {
+        self.pieces(&Piece::new(ptype, color))
+    }
+
+    pub fn king_position(&self, color: Color) -> Option<Position> {
+        self.pieces_of(&KING, color).first()
+    }
+
+    /// Every piece of either color attacking `position`, treating `occupied` as the set of
+    /// squares that block sliders.
+    pub fn attackers_to(&self, position: Position, occupied: Bitboard) -> Bitboard {
+        let rooks = |color| self.pieces_of(&ROOK, color) | self.pieces_of(&QUEEN, color);
+        let bishops = |color| self.pieces_of(&BISHOP, color) | self.pieces_of(&QUEEN, color);
+        Color::iter().fold(Bitboard::new(), |attackers, color| {
+            attackers
+                | (pieces::pawn::attacks(color.opposite(), position) & self.pieces_of(&PAWN, color))
+                | (pieces::knight::attacks(position) & self.pieces_of(&KNIGHT, color))
+                | (pieces::king::attacks(position) & self.pieces_of(&KING, color))
+                | (magic::rook_attacks(position, occupied) & rooks(color))
+                | (magic::bishop_attacks(position, occupied) & bishops(color))
+        })
+    }
+
+    pub fn is_attacked(&self, position: Position, by: Color) -> bool {
+        !(self.attackers_to(position, self.occupied()) & self.occupied_by(by)).is_empty()
+    }
+
+    /// The enemy pieces giving check to the side to move.
+    pub fn checkers(&self) -> Bitboard {
+        let color = self.current_turn;
+        self.king_position(color)
+            .map_or_else(Bitboard::new, |king| {
+                self.attackers_to(king, self.occupied()) & self.occupied_by(color.opposite())
+            })
+    }
+
+    pub fn is_check(&self) -> bool {
+        !self.checkers().is_empty()
+    }
+
+    /// Pieces of `color` that are the only blocker between their king and an enemy slider.
+    pub fn pinned(&self, color: Color) -> Bitboard {
+        let Some(king) = self.king_position(color) else {
+            return Bitboard::new();
+        };
+        let enemy = color.opposite();
+        let queens = self.pieces_of(&QUEEN, enemy);
+        let snipers = (magic::rook_attacks(king, Bitboard::new())
+            & (self.pieces_of(&ROOK, enemy) | queens))
+            | (magic::bishop_attacks(king, Bitboard::new())
+                & (self.pieces_of(&BISHOP, enemy) | queens));
+
+        let occupied = self.occupied();
+        snipers
+            .into_iter()
+            .map(|sniper| Bitboard::between(king, sniper) & occupied)
+            .filter(|blockers| blockers.count() == 1)
+            .fold(Bitboard::new(), |pinned, blockers| pinned | blockers)
+            & self.occupied_by(color)
+    }
+
+    pub fn pseudo_legal_moves(&self) -> Vec<HalfMove> {
+        let color = self.current_turn;
+        pieces::PIECE_TYPES
+            .iter()
+            .flat_map(|ptype| {
+                let piece = Piece::new(ptype, color);
+                self.pieces(&piece)
+                    .into_iter()
+                    .flat_map(move |position| piece.get_pseudo_legal_moves(self, position))
+            })
+            .collect()
+    }
+
+    /// Every move the side to move may legally play.
+    pub fn legal_moves(&self) -> Box<[HalfMove]> {
+        let color = self.current_turn;
+        let Some(king) = self.king_position(color) else {
+            return self.pseudo_legal_moves().into_boxed_slice();
+        };
+        let context = LegalityContext {
+            king,
+            checkers: self.checkers(),
+            pinned: self.pinned(color),
+        };
+
+        self.pseudo_legal_moves()
+            .into_iter()
+            .filter(|half_move| self.is_legal(half_move, &context))
+            .collect()
+    }
+
+    fn is_legal(&self, half_move: &HalfMove, context: &LegalityContext) -> bool {
+        let color = self.current_turn;
+        let enemy = self.occupied_by(color.opposite());
+        let (from, to) = (*half_move.from(), *half_move.to());
+
+        if from == context.king {
+            if let MoveKind::Castle(_) = half_move.kind() {
+                // Castling: the king may not leave, pass through or land on an attacked square.
+                return context.checkers.is_empty()
+                    && (Bitboard::between(from, to) | to.into())
+                        .into_iter()
+                        .all(|square| !self.is_attacked(square, color.opposite()));
+            }
+            let occupied = self.occupied() & !Bitboard::from(from);
+            return (self.attackers_to(to, occupied) & enemy & !Bitboard::from(to)).is_empty();
+        }
+
+        if context.checkers.count() > 1 {
+            return false;
+        }
+
+        if half_move.kind() == MoveKind::EnPassant {
+            return self.is_legal_en_passant(from, to, context.king);
+        }
+
+        if let Some(checker) = context.checkers.first() {
+            if !(Bitboard::between(context.king, checker) | checker.into()).get(to) {
+                return false;
+            }
+        }
+
+        !context.pinned.get(from) || Bitboard::line(context.king, from).get(to)
+    }
+
+    /// En passant removes two pieces from the same rank at once, which can expose the king in
+    /// ways pin detection does not see, so the resulting position is checked directly.
+    fn is_legal_en_passant(&self, from: Position, to: Position, king: Position) -> bool {
+        let captured = Position::new(*from.rank(), *to.file());
+        let occupied =
+            (self.occupied() & !Bitboard::from(from) & !Bitboard::from(captured)) | to.into();
+        let enemy = self.occupied_by(self.current_turn.opposite()) & !Bitboard::from(captured);
+        (self.attackers_to(king, occupied) & enemy).is_empty()
+    }
+}
+
+struct LegalityContext {
+    king: Position,
+    checkers: Bitboard,
+    pinned: Bitboard,
+}
@@ -0,0 +1,253 @@
+use crate::{
+    bitboard::Bitboard,
+    piece::{Color, Piece},
+    pieces::{BISHOP, KNIGHT, PAWN, QUEEN, ROOK},
+};
+
+use super::Board;
+
+/// Light squares, used to tell whether bishops can ever meet on the same color.
+const LIGHT_SQUARES: Bitboard = Bitboard::from_data(0x55AA_55AA_55AA_55AA);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Outcome {
+    /// The game was won by the given color.
+    Checkmate(Color),
+    /// The given color won because the other ran out of time.
+    Timeout(Color),
+    /// The given color won because the other suggested an illegal move or action and was not
+    /// allowed to try again.
+    IllegalMove(Color),
+    /// The given color won because the other resigned.
+    Resignation(Color),
+    Stalemate,
+    Draw(DrawReason),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DrawReason {
+    /// Fifty moves by each side without a capture or pawn move; the draw must be claimed.
+    FiftyMoveRule,
+    /// Seventy-five moves by each side without a capture or pawn move; the game ends at once.
+    SeventyFiveMoveRule,
+    /// The same position occurred three times; the draw must be claimed.
+    ThreefoldRepetition,
+    /// The same position occurred five times; the game ends at once.
+    FivefoldRepetition,
+    /// Neither side has enough material left to deliver checkmate.
+    InsufficientMaterial,
+    /// The players agreed to a draw.
+    Agreement,
+    /// One side ran out of time, but the other had too little material left to win.
+    TimeoutVsInsufficientMaterial,
+}
+
+impl Board {
+    /// The outcome if the game has ended without either player having to claim it.
+    pub fn get_outcome(&self) -> Option<Outcome> {
+        if self.legal_moves().is_empty() {
+            return Some(if self.is_check() {
+                Outcome::Checkmate(self.current_turn.opposite())
+            } else {
+                Outcome::Stalemate
+            });
+        }
+
+        if self.half_move_clock >= 150 {
+            Some(Outcome::Draw(DrawReason::SeventyFiveMoveRule))
+        } else if self.repetition_count() >= 5 {
+            Some(Outcome::Draw(DrawReason::FivefoldRepetition))
+        } else if self.is_insufficient_material() {
+            Some(Outcome::Draw(DrawReason::InsufficientMaterial))
+        } else {
+            None
+        }
+    }
+
+    /// A draw the player to move is entitled to claim in the current position, if any.
+    pub fn claimable_draw(&self) -> Option<DrawReason> {
+        if self.repetition_count() >= 3 {
+            Some(DrawReason::ThreefoldRepetition)
+        } else if self.half_move_clock >= 100 {
+            Some(DrawReason::FiftyMoveRule)
+        } else {
+            None
+        }
+    }
+
+    /// How many times the current position has occurred, including now. Only positions since
+    /// the last capture or pawn move with the same side to move can match.
+    pub fn repetition_count(&self) -> usize {
+        let key = self.hash;
+        let reversible = self.history.len().min(self.half_move_clock as usize);
+        1 + self.history[self.history.len() - reversible..]
+            .iter()
+            .rev()
+            .skip(1)
+            .step_by(2)
+            .filter(|&&previous| previous == key)
+            .count()
+    }
+
+    /// Whether `color` has enough material that it could still win, as decided when the other
+    /// side runs out of time. A lone king, or a king with a single knight or bishop, counts as
+    /// too little, even in the rare positions where the other side could help it mate.
+    pub fn has_mating_material(&self, color: Color) -> bool {
+        let pieces = |ptype| self.pieces(&Piece::new(ptype, color));
+        if !(pieces(&PAWN) | pieces(&ROOK) | pieces(&QUEEN)).is_empty() {
+            return true;
+        }
+        (pieces(&KNIGHT) | pieces(&BISHOP)).count() > 1
+    }
+
+    /// Whether checkmate is impossible for both sides: bare kings, a single minor piece, or
+    /// bishops that all stand on the same color of square.
+    pub fn is_insufficient_material(&self) -> bool {
+        let both = |ptype| {
+            self.pieces(&Piece::new(ptype, Color::White))
+                | self.pieces(&Piece::new(ptype, Color::Black))
+        };
+        if !(both(&PAWN) | both(&ROOK) | both(&QUEEN)).is_empty() {
+            return false;
+        }
+
+        let knights = both(&KNIGHT);
+        let bishops = both(&BISHOP);
+        let minors = (knights | bishops).count();
+        minors <= 1
+            || (knights.is_empty()
+                && ((bishops & LIGHT_SQUARES).is_empty() || (bishops & !LIGHT_SQUARES).is_empty()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(fen: &str) -> Board {
+        Board::from_fen(fen).expect("test FEN strings are valid")
+    }
+
+    fn play(board: &mut Board, moves: &[&str]) {
+        for san in moves {
+            let half_move = board.parse_san(san).expect("test moves are legal");
+            board.make_move(&half_move);
+        }
+    }
+
+    const KNIGHT_SHUFFLE: [&str; 4] = ["Nf3", "Nf6", "Ng1", "Ng8"];
+
+    #[test]
+    fn checkmate_is_won_by_the_side_that_delivered_it() {
+        let mut board = Board::new();
+        play(&mut board, &["f3", "e5", "g4", "Qh4#"]);
+        assert_eq!(board.get_outcome(), Some(Outcome::Checkmate(Color::Black)));
+    }
+
+    #[test]
+    fn stalemate_ends_the_game() {
+        let board = board("k7/8/1Q6/8/8/8/8/K7 b - - 0 1");
+        assert_eq!(board.get_outcome(), Some(Outcome::Stalemate));
+    }
+
+    #[test]
+    fn seventy_five_move_rule_applies_at_halfmove_150() {
+        let drawn = board("4k3/8/8/8/8/8/8/R3K3 w - - 150 100");
+        assert_eq!(
+            drawn.get_outcome(),
+            Some(Outcome::Draw(DrawReason::SeventyFiveMoveRule))
+        );
+
+        let claimable = board("4k3/8/8/8/8/8/8/R3K3 w - - 149 100");
+        assert_eq!(claimable.get_outcome(), None);
+        assert_eq!(claimable.claimable_draw(), Some(DrawReason::FiftyMoveRule));
+        assert_eq!(
+            board("4k3/8/8/8/8/8/8/R3K3 w - - 99 80").claimable_draw(),
+            None
+        );
+    }
+
+    #[test]
+    fn threefold_repetition_is_claimable_but_not_automatic() {
+        let mut board = Board::new();
+        play(&mut board, &KNIGHT_SHUFFLE);
+        assert_eq!(board.repetition_count(), 2);
+        assert_eq!(board.claimable_draw(), None);
+
+        play(&mut board, &KNIGHT_SHUFFLE);
+        assert_eq!(board.repetition_count(), 3);
+        assert_eq!(
+            board.claimable_draw(),
+            Some(DrawReason::ThreefoldRepetition)
+        );
+        assert_eq!(board.get_outcome(), None);
+    }
+
+    #[test]
+    fn fivefold_repetition_ends_the_game() {
+        let mut board = Board::new();
+        for _ in 0..3 {
+            play(&mut board, &KNIGHT_SHUFFLE);
+        }
+        assert_eq!(board.repetition_count(), 4);
+        assert_eq!(board.get_outcome(), None);
+
+        play(&mut board, &KNIGHT_SHUFFLE);
+        assert_eq!(board.repetition_count(), 5);
+        assert_eq!(
+            board.get_outcome(),
+            Some(Outcome::Draw(DrawReason::FivefoldRepetition))
+        );
+    }
+
+    #[test]
+    fn repetitions_reset_after_a_pawn_move() {
+        let mut board = Board::new();
+        play(&mut board, &KNIGHT_SHUFFLE);
+        play(&mut board, &["e4", "e5"]);
+        play(&mut board, &KNIGHT_SHUFFLE);
+        assert_eq!(board.repetition_count(), 2);
+    }
+
+    #[test]
+    fn insufficient_material_is_drawn() {
+        for fen in [
+            // King against king.
+            "4k3/8/8/8/8/8/8/4K3 w - - 0 1",
+            // King and knight against king.
+            "4k3/8/8/8/8/8/8/1N2K3 w - - 0 1",
+            // King and bishop against king and bishop, both on dark squares.
+            "5b1k/8/8/8/8/8/8/2B1K3 w - - 0 1",
+        ] {
+            let board = board(fen);
+            assert!(board.is_insufficient_material(), "{fen}");
+            assert_eq!(
+                board.get_outcome(),
+                Some(Outcome::Draw(DrawReason::InsufficientMaterial)),
+                "{fen}"
+            );
+        }
+    }
+
+    #[test]
+    fn mating_material_keeps_the_game_going() {
+        for fen in [
+            // A single pawn can still promote.
+            "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1",
+            // Bishops on opposite colors can still mate with help.
+            "2b4k/8/8/8/8/8/8/2B1K3 w - - 0 1",
+            // So can two knights.
+            "4k3/8/8/8/8/8/8/1N2K1N1 w - - 0 1",
+        ] {
+            let board = board(fen);
+            assert!(!board.is_insufficient_material(), "{fen}");
+            assert_eq!(board.get_outcome(), None, "{fen}");
+        }
+
+        let pawn = board("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1");
+        assert!(pawn.has_mating_material(Color::White));
+        assert!(!pawn.has_mating_material(Color::Black));
+        let bishop = board("4k3/8/8/8/8/8/8/2B1K3 w - - 0 1");
+        assert!(!bishop.has_mating_material(Color::White));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::half_move::HalfMove;
+
+use super::{Board, START_FEN};
+
+/// Well-known positions and their published node counts, starting at depth 1.
+pub const SUITE: [(&str, &str, &[u64]); 6] = [
+    (
+        "Start position",
+        START_FEN,
+        &[20, 400, 8902, 197_281, 4_865_609, 119_060_324],
+    ),
+    (
+        "Kiwipete",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        &[48, 2039, 97_862, 4_085_603, 193_690_690],
+    ),
+    (
+        "Position 3",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        &[14, 191, 2812, 43_238, 674_624, 11_030_083],
+    ),
+    (
+        "Position 4",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        &[6, 264, 9467, 422_333, 15_833_292],
+    ),
+    (
+        "Position 5",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        &[44, 1486, 62_379, 2_103_487, 89_941_194],
+    ),
+    (
+        "Position 6",
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+        &[46, 2079, 89_890, 3_894_594, 164_075_551],
+    ),
+];
+
+impl Board {
+    /// Counts the leaf nodes of the legal move tree `depth` plies deep.
+    pub fn perft(&self, depth: u32) -> u64 {
+        let mut board = self.clone();
+        board.perft_recursive(depth)
+    }
+
+    /// Splits the perft count by the first move played, which narrows down where move generation
+    /// disagrees with a reference engine.
+    pub fn perft_divide(&self, depth: u32) -> Vec<(HalfMove, u64)> {
+        let mut board = self.clone();
+        board
+            .legal_moves()
+            .iter()
+            .map(|half_move| {
+                let undo = board.make_move(half_move);
+                let nodes = board.perft_recursive(depth.saturating_sub(1));
+                board.unmake_move(half_move, &undo);
+                (*half_move, nodes)
+            })
+            .collect()
+    }
+
+    fn perft_recursive(&mut self, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        let moves = self.legal_moves();
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+
+        let mut nodes = 0;
+        for half_move in &moves {
+            #[cfg(debug_assertions)]
+            let before = self.clone();
+            let undo = self.make_move(half_move);
+            nodes += self.perft_recursive(depth - 1);
+            self.unmake_move(half_move, &undo);
+            #[cfg(debug_assertions)]
+            debug_assert!(*self == before, "unmake_move did not restore the board");
+        }
+        nodes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the suite position called `name` against its published counts up to `max_depth`.
+    fn assert_counts(name: &str, max_depth: usize) {
+        let (_, fen, expected_counts) = SUITE
+            .iter()
+            .find(|(suite_name, _, _)| *suite_name == name)
+            .expect("the position is in the suite");
+        let board = Board::from_fen(fen).expect("suite FEN strings are valid");
+        for (depth, &expected) in (1..).zip(expected_counts.iter().take(max_depth)) {
+            assert_eq!(board.perft(depth), expected, "{name} at depth {depth}");
+        }
+    }
+
+    #[test]
+    fn start_position() {
+        assert_counts("Start position", 4);
+    }
+
+    #[test]
+    fn kiwipete() {
+        assert_counts("Kiwipete", 3);
+    }
+
+    #[test]
+    fn position_3() {
+        assert_counts("Position 3", 5);
+    }
+
+    #[test]
+    fn position_4() {
+        assert_counts("Position 4", 3);
+    }
+
+    #[test]
+    fn position_5() {
+        assert_counts("Position 5", 3);
+    }
+
+    #[test]
+    fn position_6() {
+        assert_counts("Position 6", 3);
+    }
+
+    #[test]
+    #[ignore = "takes minutes; run with --release --ignored"]
+    fn full_suite() {
+        for (name, _, expected_counts) in SUITE {
+            assert_counts(name, expected_counts.len());
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{
+    errors::InvalidNotation,
+    half_move::{HalfMove, MoveKind},
+    pieces::{self, PieceType, KING, PAWN},
+    position::Position,
+};
+
+use super::{Board, BoardSide};
+
+const fn invalid<T>(reason: String) -> Result<T, InvalidNotation> {
+    Err(InvalidNotation { reason })
+}
+
+impl Board {
+    /// Formats a legal move in Standard Algebraic Notation, such as `Nbxd7+`, `O-O` or `e8=Q#`.
+    pub fn to_san(&self, half_move: &HalfMove) -> String {
+        let mut out = self.san_without_suffix(half_move);
+        let mut after = self.clone();
+        after.make_move(half_move);
+        if after.is_check() {
+            out.push(if after.legal_moves().is_empty() {
+                '#'
+            } else {
+                '+'
+            });
+        }
+        out
+    }
+
+    fn san_without_suffix(&self, half_move: &HalfMove) -> String {
+        let (from, to) = (*half_move.from(), *half_move.to());
+        let Some(piece) = self.get(from) else {
+            return half_move.to_string();
+        };
+        let ptype = piece.ptype();
+
+        match half_move.kind() {
+            MoveKind::Castle(BoardSide::KingSide) => return "O-O".to_string(),
+            MoveKind::Castle(BoardSide::QueenSide) => return "O-O-O".to_string(),
+            _ => {}
+        }
+
+        let is_pawn = *ptype == PAWN;
+        let is_capture = self.get(to).is_some() || half_move.kind() == MoveKind::EnPassant;
+        let mut out = String::new();
+        if is_pawn {
+            if is_capture {
+                out.push(from.file_char());
+            }
+        } else {
+            out.push(ptype.piece_char().to_ascii_uppercase());
+            out.push_str(&self.disambiguation(half_move, ptype));
+        }
+        if is_capture {
+            out.push('x');
+        }
+        out.push_str(&to.to_string());
+        if let Some(promotion) = half_move.promotion() {
+            out.push('=');
+            out.push(promotion.piece_char().to_ascii_uppercase());
+        }
+        out
+    }
+
+    /// The shortest prefix of the origin square that tells `half_move` apart from moves by other
+    /// pieces of the same type to the same square.
+    fn disambiguation(&self, half_move: &HalfMove, ptype: &PieceType) -> String {
+        let from = *half_move.from();
+        let rivals: Vec<Position> = self
+            .legal_moves()
+            .iter()
+            .filter(|other| other.to() == half_move.to() && *other.from() != from)
+            .filter(|other| {
+                self.get(*other.from())
+                    .is_some_and(|piece| piece.ptype() == ptype)
+            })
+            .map(|other| *other.from())
+            .collect();
+
+        if rivals.is_empty() {
+            String::new()
+        } else if rivals.iter().all(|rival| rival.file() != from.file()) {
+            from.file_char().to_string()
+        } else if rivals.iter().all(|rival| rival.rank() != from.rank()) {
+            from.rank_char().to_string()
+        } else {
+            from.to_string()
+        }
+    }
+
+    /// Finds the legal move described by a SAN string. Check, mate and annotation suffixes are
+    /// ignored, and castling may be written with zeroes as well as the letter O.
+    pub fn parse_san(&self, san: &str) -> Result<HalfMove, InvalidNotation> {
+        let trimmed = san.trim_end_matches(['+', '#', '!', '?']);
+        let legal_moves = self.legal_moves();
+
+        let castling_side = match trimmed {
+            "O-O" | "0-0" => Some(BoardSide::KingSide),
+            "O-O-O" | "0-0-0" => Some(BoardSide::QueenSide),
+            _ => None,
+        };
+        if let Some(side) = castling_side {
+            return legal_moves
+                .iter()
+                .find(|half_move| half_move.kind() == MoveKind::Castle(side))
+                .copied()
+                .map_or_else(
+                    || invalid(format!("castling with '{san}' is not legal")),
+                    Ok,
+                );
+        }
+
+        let mut chars: Vec<char> = trimmed.chars().collect();
+        let ptype = match chars.first() {
+            Some(&character) if character.is_ascii_uppercase() => {
+                chars.remove(0);
+                match pieces::get_piece_type_from_char(character) {
+                    Some(ptype) if *ptype != PAWN => ptype,
+                    _ => return invalid(format!("'{character}' is not a piece letter")),
+                }
+            }
+            _ => &PAWN,
+        };
+
+        let promotion = match chars.last() {
+            Some(&character) if character.is_ascii_uppercase() => {
+                chars.pop();
+                if chars.last() == Some(&'=') {
+                    chars.pop();
+                }
+                match pieces::get_piece_type_from_char(character) {
+                    Some(ptype) if *ptype != PAWN && *ptype != KING => Some(ptype),
+                    _ => return invalid(format!("cannot promote to '{character}'")),
+                }
+            }
+            _ => None,
+        };
+
+        let Some(split) = chars.len().checked_sub(2) else {
+            return invalid(format!("'{san}' has no destination square"));
+        };
+        let to = Position::try_from([chars[split], chars[split + 1]])
+            .or_else(|_| invalid(format!("'{san}' has no destination square")))?;
+        chars.truncate(split);
+        if chars.last() == Some(&'x') {
+            chars.pop();
+        }
+
+        let (mut file, mut rank) = (None, None);
+        for character in chars {
+            match character {
+                'a'..='h' => file = Some(character),
+                '1'..='8' => rank = Some(character),
+                _ => return invalid(format!("unexpected character '{character}' in '{san}'")),
+            }
+        }
+
+        let candidates: Vec<&HalfMove> = legal_moves
+            .iter()
+            .filter(|half_move| *half_move.to() == to && half_move.promotion() == promotion)
+            .filter(|half_move| {
+                let from = *half_move.from();
+                self.get(from).is_some_and(|piece| piece.ptype() == ptype)
+                    && file.is_none_or(|file| from.file_char() == file)
+                    && rank.is_none_or(|rank| from.rank_char() == rank)
+            })
+            .collect();
+        match candidates.as_slice() {
+            [half_move] => Ok(**half_move),
+            [] => invalid(format!("no legal move matches '{san}'")),
+            _ => invalid(format!("'{san}' could mean more than one move")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        board::{perft, START_FEN as START},
+        magic::Xorshift,
+    };
+
+    fn board(fen: &str) -> Board {
+        Board::from_fen(fen).expect("test FEN strings are valid")
+    }
+
+    fn san(fen: &str, uci: &str) -> String {
+        let board = board(fen);
+        let half_move = board.parse_uci(uci).expect("test moves are legal");
+        board.to_san(&half_move)
+    }
+
+    fn parses_to(fen: &str, san: &str, uci: &str) {
+        let board = board(fen);
+        match board.parse_san(san) {
+            Ok(half_move) => assert_eq!(half_move.to_string(), uci, "{san}"),
+            Err(error) => panic!("'{san}' was rejected: {}", error.reason),
+        }
+    }
+
+    const KNIGHTS: &str = "4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1";
+    const ROOKS: &str = "4k3/8/8/R7/8/8/8/R3K3 w - - 0 1";
+    const QUEENS: &str = "4k3/8/8/8/8/Q1Q5/8/Q3K3 w - - 0 1";
+    const CASTLING: &str = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+
+    #[test]
+    fn disambiguates_by_file_then_rank_then_square() {
+        assert_eq!(san(KNIGHTS, "b1d2"), "Nbd2");
+        assert_eq!(san(KNIGHTS, "f1d2"), "Nfd2");
+        assert_eq!(san(KNIGHTS, "b1c3"), "Nc3");
+        assert_eq!(san(ROOKS, "a1a3"), "R1a3");
+        assert_eq!(san(ROOKS, "a5a3"), "R5a3");
+        assert_eq!(san(QUEENS, "a1b2"), "Q1b2");
+        assert_eq!(san(QUEENS, "a3b2"), "Qa3b2");
+        assert_eq!(san(QUEENS, "c3b2"), "Qcb2");
+
+        parses_to(KNIGHTS, "Nbd2", "b1d2");
+        parses_to(ROOKS, "R5a3", "a5a3");
+        parses_to(QUEENS, "Qa3b2", "a3b2");
+        parses_to(QUEENS, "Qa3xb2", "a3b2");
+    }
+
+    #[test]
+    fn formats_promotions_and_captures() {
+        let fen = "1n2k3/P7/8/8/8/8/8/4K3 w - - 0 1";
+        assert_eq!(san(fen, "a7b8q"), "axb8=Q+");
+        assert_eq!(san(fen, "a7b8n"), "axb8=N");
+        assert_eq!(san(fen, "a7a8r"), "a8=R");
+        parses_to(fen, "axb8=Q+", "a7b8q");
+        parses_to(fen, "axb8N", "a7b8n");
+        assert_eq!(san("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6"), "exd6");
+    }
+
+    #[test]
+    fn formats_and_parses_castling() {
+        assert_eq!(san(CASTLING, "e1g1"), "O-O");
+        assert_eq!(san(CASTLING, "e1c1"), "O-O-O");
+        parses_to(CASTLING, "O-O", "e1g1");
+        parses_to(CASTLING, "0-0-0", "e1c1");
+        parses_to("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1", "O-O-O", "e8c8");
+    }
+
+    #[test]
+    fn appends_check_and_mate_suffixes() {
+        assert_eq!(san("4k3/8/8/8/8/8/8/R3K3 w - - 0 1", "a1a8"), "Ra8+");
+        assert_eq!(san("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "a1a8"), "Ra8#");
+        assert_eq!(san("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "a1a7"), "Ra7");
+        parses_to("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "Ra8#", "a1a8");
+        parses_to("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "Ra8!?", "a1a8");
+    }
+
+    #[test]
+    fn rejects_ambiguous_and_illegal_moves() {
+        for (fen, san) in [
+            (KNIGHTS, "Nd2"),
+            (ROOKS, "Ra3"),
+            (QUEENS, "Qb2"),
+            (QUEENS, "Qab2"),
+            (START, "e5"),
+            (START, "Nd4"),
+            (START, "O-O"),
+            (START, "Ke2"),
+            (START, "Zf3"),
+            (START, "e9"),
+            (START, "x"),
+            (START, ""),
+            (START, "Pe4"),
+            ("1n2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "axb8=K"),
+            ("1n2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "axb8"),
+        ] {
+            assert!(board(fen).parse_san(san).is_err(), "'{san}' in {fen}");
+        }
+    }
+
+    /// Every legal move in every position reached by random games from the perft suite formats
+    /// to SAN that parses back to the same move.
+    #[test]
+    fn round_trips_every_legal_move() {
+        let mut rng = Xorshift::new(0xD1B5_4A32_D192_ED03);
+        for (name, fen, _) in perft::SUITE {
+            for _ in 0..3 {
+                let mut board = board(fen);
+                for _ in 0..40 {
+                    let moves = board.legal_moves();
+                    for half_move in &moves {
+                        let san = board.to_san(half_move);
+                        match board.parse_san(&san) {
+                            Ok(parsed) => assert_eq!(parsed, *half_move, "{name}: {san}"),
+                            Err(error) => panic!("{name}: '{san}' failed: {}", error.reason),
+                        }
+                    }
+                    let Some(half_move) = rng.choose(&moves) else {
+                        break;
+                    };
+                    board.make_move(half_move);
+                }
+            }
+        }
+    }
+}
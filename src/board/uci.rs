@@ -0,0 +1,98 @@
+use crate::{
+    errors::InvalidNotation,
+    half_move::{HalfMove, MoveKind},
+    position::Position,
+};
+
+use super::Board;
+
+impl Board {
+    /// Finds the legal move described by UCI long algebraic notation, such as `e2e4` or `e7e8q`.
+    /// Castling may be written as the king moving two squares or, as Chess960 GUIs do, as the
+    /// king capturing its own rook.
+    pub fn parse_uci(&self, uci: &str) -> Result<HalfMove, InvalidNotation> {
+        let parsed: HalfMove = uci.parse()?;
+        self.legal_moves()
+            .iter()
+            .find(|legal_move| {
+                legal_move.same_squares(&parsed)
+                    || matches!(legal_move.kind(), MoveKind::Castle(side)
+                        if legal_move.from() == parsed.from()
+                            && *parsed.to() == Position::new(*parsed.from().rank(), side.rook_file()))
+            })
+            .copied()
+            .ok_or_else(|| InvalidNotation {
+                reason: format!("'{uci}' is not a legal move"),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        board::{BoardSide, START_FEN},
+        pieces::ROOK,
+    };
+
+    const CASTLING: &str = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+
+    fn parse(fen: &str, uci: &str) -> Result<HalfMove, InvalidNotation> {
+        Board::from_fen(fen)
+            .expect("test FEN strings are valid")
+            .parse_uci(uci)
+    }
+
+    #[test]
+    fn resolves_the_kind_of_move() {
+        let start = START_FEN;
+        assert_eq!(
+            parse(start, "e2e4").unwrap().kind(),
+            MoveKind::DoublePawnPush
+        );
+        assert_eq!(parse(start, "g1f3").unwrap().kind(), MoveKind::Normal);
+        let en_passant = parse("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6").unwrap();
+        assert_eq!(en_passant.kind(), MoveKind::EnPassant);
+    }
+
+    #[test]
+    fn accepts_promotions() {
+        let half_move = parse("4k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7a8r").unwrap();
+        assert!(half_move.promotion() == Some(&ROOK));
+        assert_eq!(half_move.to_string(), "a7a8r");
+        assert!(parse("4k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7a8").is_err());
+    }
+
+    #[test]
+    fn accepts_standard_and_chess960_castling() {
+        for (fen, standard, chess960, side) in [
+            (CASTLING, "e1g1", "e1h1", BoardSide::KingSide),
+            (CASTLING, "e1c1", "e1a1", BoardSide::QueenSide),
+            (
+                "r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1",
+                "e8c8",
+                "e8a8",
+                BoardSide::QueenSide,
+            ),
+        ] {
+            let from_standard = parse(fen, standard).unwrap();
+            let from_chess960 = parse(fen, chess960).unwrap();
+            assert_eq!(from_standard, from_chess960);
+            assert_eq!(from_standard.kind(), MoveKind::Castle(side));
+            assert_eq!(from_standard.to_uci(false), standard);
+            assert_eq!(from_standard.to_uci(true), chess960);
+        }
+    }
+
+    #[test]
+    fn rejects_illegal_moves() {
+        let start = START_FEN;
+        for uci in ["e2e5", "e1g1", "e1h1", "d1d3", "e7e5", "a2a1q", "e2e"] {
+            assert!(parse(start, uci).is_err(), "'{uci}' was accepted");
+        }
+        // Castling through check is not legal in either notation.
+        let attacked = "r3k2r/8/8/8/8/8/5r2/R3K2R w KQkq - 0 1";
+        assert!(parse(attacked, "e1g1").is_err());
+        assert!(parse(attacked, "e1h1").is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use std::time::Duration;
+
+use enum_map::EnumMap;
+
+use crate::piece::Color;
+
+/// Time that passes at the start of every move before the clock is charged.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Delay {
+    /// The clock runs from the start, and afterwards gives back the time used, up to the delay.
+    Bronstein(Duration),
+    /// The clock only starts once the delay has passed.
+    Simple(Duration),
+}
+
+impl Delay {
+    pub const fn duration(self) -> Duration {
+        match self {
+            Self::Bronstein(duration) | Self::Simple(duration) => duration,
+        }
+    }
+}
+
+/// How much time each side gets: `base` to start with, `increment` after every move, and
+/// `base` again after every `moves_per_period` moves if that is set.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TimeControl {
+    pub base: Duration,
+    pub increment: Duration,
+    pub moves_per_period: Option<u32>,
+    pub delay: Option<Delay>,
+}
+
+impl TimeControl {
+    /// A sudden-death control with a Fischer increment, which may be zero.
+    pub const fn new(base: Duration, increment: Duration) -> Self {
+        Self {
+            base,
+            increment,
+            moves_per_period: None,
+            delay: None,
+        }
+    }
+
+    /// Adds `base` to the clock again after every `moves` moves, as in classical controls
+    /// such as 40 moves in 90 minutes.
+    #[must_use]
+    pub const fn with_moves_per_period(self, moves: u32) -> Self {
+        assert!(moves > 0, "a period has at least one move");
+        Self {
+            moves_per_period: Some(moves),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn with_delay(self, delay: Delay) -> Self {
+        Self {
+            delay: Some(delay),
+            ..self
+        }
+    }
+}
+
+/// Each side's remaining time under a [`TimeControl`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Clock {
+    control: TimeControl,
+    remaining: EnumMap<Color, Duration>,
+    moves: EnumMap<Color, u32>,
+}
+
+impl Clock {
+    pub fn new(control: TimeControl) -> Self {
+        Self {
+            control,
+            remaining: EnumMap::from_fn(|_| control.base),
+            moves: EnumMap::default(),
+        }
+    }
+
+    pub const fn control(&self) -> &TimeControl {
+        &self.control
+    }
+
+    pub fn remaining(&self, color: Color) -> Duration {
+        self.remaining[color]
+    }
+
+    /// How many moves `color` has left to make before the next period adds time, if the
+    /// control has periods.
+    pub fn moves_to_go(&self, color: Color) -> Option<u32> {
+        self.control
+            .moves_per_period
+            .map(|moves| moves - self.moves[color] % moves)
+    }
+
+    /// How long `color` may think about its current move before its flag falls.
+    pub fn time_to_flag(&self, color: Color) -> Duration {
+        match self.control.delay {
+            Some(Delay::Simple(delay)) => self.remaining[color] + delay,
+            Some(Delay::Bronstein(_)) | None => self.remaining[color],
+        }
+    }
+
+    /// Runs `color`'s clock down to zero, as when its flag fell before it finished a move.
+    pub fn flag(&mut self, color: Color) {
+        self.remaining[color] = Duration::ZERO;
+    }
+
+    /// Charges `color` for a move that took `elapsed`, then adds the increment and any new
+    /// period. Returns `false` if the flag fell during the move, leaving the clock at zero.
+    pub fn finish_move(&mut self, color: Color, elapsed: Duration) -> bool {
+        let charged = match self.control.delay {
+            Some(Delay::Simple(delay)) => elapsed.saturating_sub(delay),
+            Some(Delay::Bronstein(_)) | None => elapsed,
+        };
+        let Some(remaining) = self.remaining[color].checked_sub(charged) else {
+            self.remaining[color] = Duration::ZERO;
+            return false;
+        };
+
+        let refund = match self.control.delay {
+            Some(Delay::Bronstein(delay)) => elapsed.min(delay),
+            Some(Delay::Simple(_)) | None => Duration::ZERO,
+        };
+        self.remaining[color] = remaining + refund + self.control.increment;
+        self.moves[color] += 1;
+        if self
+            .control
+            .moves_per_period
+            .is_some_and(|moves| self.moves[color].is_multiple_of(moves))
+        {
+            self.remaining[color] += self.control.base;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn seconds(seconds: u64) -> Duration {
+        Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn fischer_increment_is_added_after_the_move() {
+        let mut clock = Clock::new(TimeControl::new(seconds(60), seconds(2)));
+        assert!(clock.finish_move(Color::White, seconds(5)));
+        assert_eq!(clock.remaining(Color::White), seconds(57));
+        assert_eq!(clock.remaining(Color::Black), seconds(60));
+        assert_eq!(clock.time_to_flag(Color::White), seconds(57));
+        assert_eq!(clock.moves_to_go(Color::White), None);
+    }
+
+    #[test]
+    fn bronstein_delay_refunds_up_to_the_delay() {
+        let control =
+            TimeControl::new(seconds(60), Duration::ZERO).with_delay(Delay::Bronstein(seconds(3)));
+        let mut clock = Clock::new(control);
+        assert!(clock.finish_move(Color::White, seconds(2)));
+        assert_eq!(clock.remaining(Color::White), seconds(60));
+        assert!(clock.finish_move(Color::White, seconds(5)));
+        assert_eq!(clock.remaining(Color::White), seconds(58));
+        // The refund comes after the move, so it cannot save a flag.
+        assert_eq!(clock.time_to_flag(Color::White), seconds(58));
+        assert!(!clock.finish_move(Color::White, seconds(59)));
+    }
+
+    #[test]
+    fn simple_delay_runs_before_the_clock_starts() {
+        let control =
+            TimeControl::new(seconds(60), Duration::ZERO).with_delay(Delay::Simple(seconds(3)));
+        let mut clock = Clock::new(control);
+        assert!(clock.finish_move(Color::Black, seconds(2)));
+        assert_eq!(clock.remaining(Color::Black), seconds(60));
+        assert!(clock.finish_move(Color::Black, seconds(5)));
+        assert_eq!(clock.remaining(Color::Black), seconds(58));
+        assert_eq!(clock.time_to_flag(Color::Black), seconds(61));
+        assert!(clock.finish_move(Color::Black, seconds(61)));
+        assert_eq!(clock.remaining(Color::Black), Duration::ZERO);
+    }
+
+    #[test]
+    fn periods_add_the_base_time_again() {
+        let control = TimeControl::new(seconds(10), seconds(1)).with_moves_per_period(2);
+        let mut clock = Clock::new(control);
+        assert_eq!(clock.moves_to_go(Color::White), Some(2));
+        assert!(clock.finish_move(Color::White, seconds(4)));
+        assert_eq!(clock.moves_to_go(Color::White), Some(1));
+        assert_eq!(clock.remaining(Color::White), seconds(7));
+        assert!(clock.finish_move(Color::White, seconds(4)));
+        assert_eq!(clock.moves_to_go(Color::White), Some(2));
+        assert_eq!(clock.remaining(Color::White), seconds(14));
+        assert_eq!(clock.moves_to_go(Color::Black), Some(2));
+    }
+
+    #[test]
+    fn flag_falls_when_the_move_takes_too_long() {
+        let mut clock = Clock::new(TimeControl::new(seconds(10), seconds(5)));
+        assert!(!clock.finish_move(Color::White, seconds(11)));
+        assert_eq!(clock.remaining(Color::White), Duration::ZERO);
+
+        let mut clock = Clock::new(TimeControl::new(seconds(10), seconds(5)));
+        clock.flag(Color::Black);
+        assert_eq!(clock.remaining(Color::Black), Duration::ZERO);
+        assert_eq!(clock.remaining(Color::White), seconds(10));
+    }
+
+    #[test]
+    #[should_panic = "a period has at least one move"]
+    fn periods_need_a_move() {
+        let _ = TimeControl::new(seconds(10), Duration::ZERO).with_moves_per_period(0);
+    }
+}
@@ -1,10 +1,53 @@
+use std::fmt::Display;
+
 #[derive(Debug)]
 pub struct InvalidChar;
 #[derive(Debug)]
-pub struct InvalidFenString;
+pub struct InvalidFenString {
+    pub field: FenField,
+    pub reason: String,
+}
 #[derive(Debug)]
 pub struct InvalidMove {
     pub reason: String,
 }
 #[derive(Debug)]
+pub struct InvalidNetwork {
+    pub reason: String,
+}
+#[derive(Debug)]
+pub struct InvalidNotation {
+    pub reason: String,
+}
+#[derive(Debug)]
 pub struct PositionOutOfBounds;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FenField {
+    PiecePlacement,
+    ActiveColor,
+    CastlingRights,
+    EnPassantTarget,
+    HalfMoveClock,
+    FullMoveClock,
+}
+
+impl Display for FenField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::PiecePlacement => "piece placement",
+            Self::ActiveColor => "active color",
+            Self::CastlingRights => "castling rights",
+            Self::EnPassantTarget => "en passant target",
+            Self::HalfMoveClock => "halfmove clock",
+            Self::FullMoveClock => "fullmove number",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Display for InvalidFenString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid {} field: {}", self.field, self.reason)
+    }
+}
@@ -0,0 +1,522 @@
+use std::{
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, Neg, Sub},
+};
+
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::{
+    bitboard::Bitboard,
+    board::Board,
+    magic,
+    piece::{Color, Piece},
+    pieces::{self, PieceType, BISHOP, KNIGHT, PAWN, PIECE_TYPES, QUEEN, ROOK},
+    position::{Axis, Position},
+};
+
+mod weights;
+
+pub use weights::WEIGHTS;
+
+// Where each group of parameters starts in `WEIGHTS`. Piece types are in `PIECE_TYPES` order,
+// and squares and ranks are seen from the side the parameter applies to.
+pub const MATERIAL: usize = 0;
+pub const PIECE_SQUARE: usize = MATERIAL + 6;
+pub const MOBILITY: usize = PIECE_SQUARE + 6 * 64;
+pub const DOUBLED_PAWN: usize = MOBILITY + 6;
+pub const ISOLATED_PAWN: usize = DOUBLED_PAWN + 1;
+pub const PASSED_PAWN: usize = ISOLATED_PAWN + 1;
+pub const KING_SHIELD: usize = PASSED_PAWN + 8;
+pub const KING_ATTACK: usize = KING_SHIELD + 1;
+pub const BISHOP_PAIR: usize = KING_ATTACK + 1;
+pub const PARAM_COUNT: usize = BISHOP_PAIR + 1;
+
+/// The game phase with every minor and major piece still on the board. Phases above this, from
+/// promotions, count as this.
+pub const MAX_PHASE: i32 = 24;
+/// How much each piece type, in `PIECE_TYPES` order, contributes to the game phase.
+const PHASE_WEIGHTS: [i32; 6] = [0, 1, 1, 2, 4, 0];
+const FILE_A: u64 = 0x0101_0101_0101_0101;
+
+/// A middlegame and an endgame value, blended by [`Score::taper`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Score {
+    pub mg: i32,
+    pub eg: i32,
+}
+
+impl Score {
+    pub const fn new(mg: i32, eg: i32) -> Self {
+        Self { mg, eg }
+    }
+
+    /// Blends the two values by `phase`, which runs from [`MAX_PHASE`] in the opening to 0 in a
+    /// pawn ending.
+    pub const fn taper(self, phase: i32) -> i32 {
+        (self.mg * phase + self.eg * (MAX_PHASE - phase)) / MAX_PHASE
+    }
+}
+
+impl Add for Score {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.mg + rhs.mg, self.eg + rhs.eg)
+    }
+}
+
+impl AddAssign for Score {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Score {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Neg for Score {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.mg, -self.eg)
+    }
+}
+
+impl Mul<i32> for Score {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self {
+        Self::new(self.mg * rhs, self.eg * rhs)
+    }
+}
+
+impl Sum for Score {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// The parts of the evaluation reported separately by [`explain`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, EnumIter)]
+pub enum Term {
+    Material,
+    PieceSquare,
+    Mobility,
+    PawnStructure,
+    KingSafety,
+    BishopPair,
+}
+
+impl Term {
+    /// The term a parameter of [`WEIGHTS`] belongs to.
+    pub const fn of(param: usize) -> Self {
+        if param < PIECE_SQUARE {
+            Self::Material
+        } else if param < MOBILITY {
+            Self::PieceSquare
+        } else if param < DOUBLED_PAWN {
+            Self::Mobility
+        } else if param < KING_SHIELD {
+            Self::PawnStructure
+        } else if param < BISHOP_PAIR {
+            Self::KingSafety
+        } else {
+            Self::BishopPair
+        }
+    }
+}
+
+impl Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Material => "Material",
+            Self::PieceSquare => "Piece-square",
+            Self::Mobility => "Mobility",
+            Self::PawnStructure => "Pawn structure",
+            Self::KingSafety => "King safety",
+            Self::BishopPair => "Bishop pair",
+        };
+        f.pad(name)
+    }
+}
+
+/// The evaluation of a board broken down by term, from White's point of view.
+pub struct Explanation {
+    pub phase: i32,
+    pub terms: Vec<(Term, Score)>,
+    pub total: Score,
+}
+
+impl Explanation {
+    /// The blended total in centipawns, from White's point of view.
+    pub const fn score(&self) -> i32 {
+        self.total.taper(self.phase)
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<16}{:>8}{:>8}{:>8}", "Term", "MG", "EG", "Blend")?;
+        for (term, score) in &self.terms {
+            writeln!(
+                f,
+                "{term:<16}{:>8}{:>8}{:>8}",
+                score.mg,
+                score.eg,
+                score.taper(self.phase)
+            )?;
+        }
+        writeln!(
+            f,
+            "{:<16}{:>8}{:>8}{:>8}",
+            "Total",
+            self.total.mg,
+            self.total.eg,
+            self.score()
+        )?;
+        write!(f, "Phase {}/{MAX_PHASE}", self.phase)
+    }
+}
+
+/// Scores `board` in centipawns from the point of view of the side to move.
+pub fn evaluate(board: &Board) -> i32 {
+    let mut total = Score::default();
+    for_each_feature(board, |param, count| total += WEIGHTS[param] * count);
+    let score = total.taper(phase(board));
+    if board.current_turn() == Color::White {
+        score
+    } else {
+        -score
+    }
+}
+
+pub fn explain(board: &Board) -> Explanation {
+    let mut terms: Vec<(Term, Score)> = Term::iter().map(|term| (term, Score::default())).collect();
+    for_each_feature(board, |param, count| {
+        terms[Term::of(param) as usize].1 += WEIGHTS[param] * count;
+    });
+    Explanation {
+        phase: phase(board),
+        total: terms.iter().map(|(_, score)| *score).sum(),
+        terms,
+    }
+}
+
+/// How much material is left, from [`MAX_PHASE`] with every piece on the board down to 0 with
+/// only kings and pawns.
+pub fn phase(board: &Board) -> i32 {
+    let phase: i32 = PIECE_TYPES
+        .iter()
+        .zip(PHASE_WEIGHTS)
+        .map(|(ptype, weight)| {
+            let count = Color::iter()
+                .map(|color| board.pieces(&Piece::new(ptype, color)).count())
+                .sum::<u32>();
+            weight * count.cast_signed()
+        })
+        .sum();
+    phase.min(MAX_PHASE)
+}
+
+/// Calls `visit` with every parameter that applies to `board` and how many times it applies,
+/// counting White's positively and Black's negatively. The evaluation is the sum of each count
+/// times the parameter's weight, which also makes the counts its gradient for tuning.
+pub fn for_each_feature(board: &Board, mut visit: impl FnMut(usize, i32)) {
+    let occupied = board.occupied();
+    for color in Color::iter() {
+        let sign = if color == Color::White { 1 } else { -1 };
+        let own = board.occupied_by(color);
+        for ptype in PIECE_TYPES {
+            for position in board.pieces(&Piece::new(ptype, color)) {
+                visit(MATERIAL + ptype.index(), sign);
+                visit(
+                    PIECE_SQUARE + ptype.index() * 64 + relative_index(color, position),
+                    sign,
+                );
+                let reach = (attacks(ptype, position, occupied) & !own).count();
+                if reach > 0 {
+                    visit(MOBILITY + ptype.index(), sign * reach.cast_signed());
+                }
+            }
+        }
+
+        pawn_structure(board, color, sign, &mut visit);
+        king_safety(board, color, sign, occupied, &mut visit);
+        if board.pieces(&Piece::new(&BISHOP, color)).count() >= 2 {
+            visit(BISHOP_PAIR, sign);
+        }
+    }
+}
+
+fn pawn_structure(board: &Board, color: Color, sign: i32, visit: &mut impl FnMut(usize, i32)) {
+    let pawns = board.pieces(&Piece::new(&PAWN, color));
+    let enemy_pawns = board.pieces(&Piece::new(&PAWN, color.opposite()));
+    for file in Axis::iter() {
+        let on_file = (pawns & file_mask(file)).count();
+        if on_file > 1 {
+            visit(DOUBLED_PAWN, sign * (on_file - 1).cast_signed());
+        }
+    }
+
+    for pawn in pawns {
+        let neighbours = adjacent_files(*pawn.file());
+        if (pawns & neighbours).is_empty() {
+            visit(ISOLATED_PAWN, sign);
+        }
+        let ahead = ranks_ahead(color, *pawn.rank());
+        let own_blocker = pawns & ahead & file_mask(*pawn.file());
+        let enemy_guards = enemy_pawns & ahead & (neighbours | file_mask(*pawn.file()));
+        if own_blocker.is_empty() && enemy_guards.is_empty() {
+            visit(PASSED_PAWN + relative_rank(color, *pawn.rank()), sign);
+        }
+    }
+}
+
+/// Rewards pawns sheltering the king and penalizes enemy attacks on the squares around it.
+fn king_safety(
+    board: &Board,
+    color: Color,
+    sign: i32,
+    occupied: Bitboard,
+    visit: &mut impl FnMut(usize, i32),
+) {
+    let Some(king) = board.king_position(color) else {
+        return;
+    };
+    let forward = if color == Color::White { 1 } else { -1 };
+    let pawns = board.pieces(&Piece::new(&PAWN, color));
+    let shield = [forward, 2 * forward]
+        .into_iter()
+        .flat_map(|rank_offset| (-1..=1).filter_map(move |file| king.offset(rank_offset, file)))
+        .filter(|&square| pawns.get(square))
+        .count();
+    if shield > 0 {
+        visit(
+            KING_SHIELD,
+            sign * i32::try_from(shield).expect("at most six shield pawns"),
+        );
+    }
+
+    let zone = pieces::king::attacks(king) | king.into();
+    let attacks: u32 = PIECE_TYPES
+        .iter()
+        .flat_map(|ptype| {
+            board
+                .pieces(&Piece::new(ptype, color.opposite()))
+                .into_iter()
+                .map(move |position| (attacks(ptype, position, occupied) & zone).count())
+        })
+        .sum();
+    if attacks > 0 {
+        visit(KING_ATTACK, sign * attacks.cast_signed());
+    }
+}
+
+/// The squares a knight or slider attacks. Pawns and kings are left out, as their reach says
+/// little about their activity.
+fn attacks(ptype: &PieceType, position: Position, occupied: Bitboard) -> Bitboard {
+    if *ptype == KNIGHT {
+        pieces::knight::attacks(position)
+    } else if *ptype == BISHOP {
+        magic::bishop_attacks(position, occupied)
+    } else if *ptype == ROOK {
+        magic::rook_attacks(position, occupied)
+    } else if *ptype == QUEEN {
+        magic::queen_attacks(position, occupied)
+    } else {
+        Bitboard::new()
+    }
+}
+
+/// The square's index with the board flipped for Black, so both sides share one table.
+const fn relative_index(color: Color, position: Position) -> usize {
+    match color {
+        Color::White => position.index(),
+        Color::Black => position.index() ^ 0b11_1000,
+    }
+}
+
+const fn relative_rank(color: Color, rank: Axis) -> usize {
+    match color {
+        Color::White => rank as usize,
+        Color::Black => 7 - rank as usize,
+    }
+}
+
+const fn file_mask(file: Axis) -> Bitboard {
+    Bitboard::from_data(FILE_A << file as u32)
+}
+
+fn adjacent_files(file: Axis) -> Bitboard {
+    let left = (file as usize).checked_sub(1).and_then(Axis::from_repr);
+    let right = Axis::from_repr(file as usize + 1);
+    [left, right]
+        .into_iter()
+        .flatten()
+        .fold(Bitboard::new(), |mask, file| mask | file_mask(file))
+}
+
+/// Every square on the ranks in front of `rank` from `color`'s side of the board.
+const fn ranks_ahead(color: Color, rank: Axis) -> Bitboard {
+    let rank = rank as u32;
+    Bitboard::from_data(match color {
+        Color::White if rank == 7 => 0,
+        Color::White => u64::MAX << (8 * (rank + 1)),
+        Color::Black => (1u64 << (8 * rank)) - 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{board::perft, magic::Xorshift};
+
+    /// The same position with the board flipped top to bottom and the colors swapped, so Black
+    /// stands where White stood and the other side is to move.
+    fn mirror(fen: &str) -> String {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        let swap_case = |text: &str| -> String {
+            text.chars()
+                .map(|c| {
+                    if c.is_ascii_uppercase() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        c.to_ascii_uppercase()
+                    }
+                })
+                .collect()
+        };
+        let placement = fields[0].split('/').rev().collect::<Vec<_>>().join("/");
+        let turn = if fields[1] == "w" { "b" } else { "w" };
+        let mut castling: Vec<char> = swap_case(fields[2]).chars().collect();
+        castling.sort_by_key(|c| (c.is_ascii_lowercase(), *c != 'K' && *c != 'k'));
+        let castling: String = castling.into_iter().collect();
+        let en_passant = fields[3]
+            .chars()
+            .map(|c| match c {
+                '3' => '6',
+                '6' => '3',
+                _ => c,
+            })
+            .collect::<String>();
+        format!(
+            "{} {turn} {castling} {en_passant} {}",
+            swap_case(&placement),
+            fields[4..].join(" ")
+        )
+    }
+
+    fn board(fen: &str) -> Board {
+        Board::from_fen(fen).expect("test FEN strings are valid")
+    }
+
+    /// Positions from random games played out of every perft position.
+    fn sample_positions() -> Vec<String> {
+        let mut rng = Xorshift::new(0xA076_1D64_78BD_642F);
+        let mut fens = Vec::new();
+        for (_, fen, _) in perft::SUITE {
+            for _ in 0..4 {
+                let mut board = board(fen);
+                for _ in 0..40 {
+                    fens.push(board.to_fen());
+                    let Some(half_move) = rng.choose(&board.legal_moves()).copied() else {
+                        break;
+                    };
+                    board.make_move(&half_move);
+                }
+            }
+        }
+        fens
+    }
+
+    #[test]
+    fn mirror_swaps_everything() {
+        assert_eq!(
+            mirror("r3k2r/8/8/3pP3/8/8/8/R3K2R w Kq d6 0 2"),
+            "r3k2r/8/8/8/3Pp3/8/8/R3K2R b Qk d3 0 2"
+        );
+        let fen = "rnbqkbnr/pp1ppppp/8/2p5/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2";
+        assert_eq!(mirror(&mirror(fen)), fen);
+    }
+
+    #[test]
+    fn colors_are_symmetric() {
+        for fen in sample_positions() {
+            let mirrored = mirror(&fen);
+            let (original, flipped) = (board(&fen), board(&mirrored));
+            assert_eq!(
+                explain(&original).score(),
+                -explain(&flipped).score(),
+                "{fen} / {mirrored}"
+            );
+            // The side to move swaps as well, so its view of the position is unchanged.
+            assert_eq!(
+                evaluate(&original),
+                evaluate(&flipped),
+                "{fen} / {mirrored}"
+            );
+        }
+    }
+
+    #[test]
+    fn start_position_is_level() {
+        let start = Board::new();
+        assert_eq!(evaluate(&start), 0);
+        assert_eq!(explain(&start).total, Score::default());
+    }
+
+    #[test]
+    fn explained_terms_add_up_to_the_evaluation() {
+        for fen in sample_positions() {
+            let board = board(&fen);
+            let explanation = explain(&board);
+            let sum: Score = explanation.terms.iter().map(|(_, score)| *score).sum();
+            assert_eq!(sum, explanation.total, "{fen}");
+            assert_eq!(explanation.phase, phase(&board), "{fen}");
+            let white_view = if board.current_turn() == Color::White {
+                evaluate(&board)
+            } else {
+                -evaluate(&board)
+            };
+            assert_eq!(explanation.score(), white_view, "{fen}");
+        }
+    }
+
+    #[test]
+    fn tapers_from_middlegame_to_endgame() {
+        let score = Score::new(100, 300);
+        assert_eq!(score.taper(MAX_PHASE), 100);
+        assert_eq!(score.taper(0), 300);
+        assert_eq!(score.taper(MAX_PHASE / 2), 200);
+
+        assert_eq!(phase(&Board::new()), MAX_PHASE);
+        assert_eq!(
+            phase(&board("4k3/pppppppp/8/8/8/8/PPPPPPPP/4K3 w - - 0 1")),
+            0
+        );
+        assert_eq!(phase(&board("3qk3/8/8/8/8/8/8/3QK3 w - - 0 1")), 8);
+        assert_eq!(phase(&board("3qk1n1/8/8/8/8/8/8/R2QK1B1 w - - 0 1")), 12);
+        // Extra queens from promotions do not push the phase past the opening.
+        assert_eq!(
+            phase(&board("qqqqkqqq/8/8/8/8/8/8/QQQQKQQQ w - - 0 1")),
+            MAX_PHASE
+        );
+
+        // With only kings and pawns left, the endgame values alone decide.
+        let ending = explain(&board("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1"));
+        assert_eq!(ending.phase, 0);
+        assert_eq!(ending.score(), ending.total.eg);
+        let opening = explain(&board(
+            "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3",
+        ));
+        assert_eq!(opening.phase, MAX_PHASE);
+        assert_eq!(opening.score(), opening.total.mg);
+    }
+}
@@ -0,0 +1,79 @@
+//! Evaluation weights: a middlegame and endgame value for every parameter, in the order of the
+//! parameter offsets in `eval`. These are hand-set defaults, not tuner output. Running
+//! `cargo run --release --bin tune` overwrites this file with weights tuned from them.
+
+use super::{Score, PARAM_COUNT};
+
+#[rustfmt::skip]
+pub static WEIGHTS: [Score; PARAM_COUNT] = [
+    // Material: pawn, knight, bishop, rook, queen, king
+    Score::new(100, 120), Score::new(320, 300), Score::new(330, 320), Score::new(500, 550), Score::new(900, 950), Score::new(0, 0),
+    // Piece-square table: pawn, from a1 to h8
+    Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0),
+    Score::new(5, 0), Score::new(10, 0), Score::new(10, 0), Score::new(-20, 0), Score::new(-20, 0), Score::new(10, 0), Score::new(10, 0), Score::new(5, 0),
+    Score::new(5, 0), Score::new(-5, 0), Score::new(-10, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-10, 0), Score::new(-5, 0), Score::new(5, 0),
+    Score::new(0, 5), Score::new(0, 5), Score::new(0, 5), Score::new(20, 5), Score::new(20, 5), Score::new(0, 5), Score::new(0, 5), Score::new(0, 5),
+    Score::new(5, 15), Score::new(5, 15), Score::new(10, 15), Score::new(25, 15), Score::new(25, 15), Score::new(10, 15), Score::new(5, 15), Score::new(5, 15),
+    Score::new(10, 30), Score::new(10, 30), Score::new(20, 30), Score::new(30, 30), Score::new(30, 30), Score::new(20, 30), Score::new(10, 30), Score::new(10, 30),
+    Score::new(50, 60), Score::new(50, 60), Score::new(50, 60), Score::new(50, 60), Score::new(50, 60), Score::new(50, 60), Score::new(50, 60), Score::new(50, 60),
+    Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0),
+    // Piece-square table: knight, from a1 to h8
+    Score::new(-50, -50), Score::new(-40, -40), Score::new(-30, -30), Score::new(-30, -30), Score::new(-30, -30), Score::new(-30, -30), Score::new(-40, -40), Score::new(-50, -50),
+    Score::new(-40, -40), Score::new(-20, -20), Score::new(0, 0), Score::new(5, 5), Score::new(5, 5), Score::new(0, 0), Score::new(-20, -20), Score::new(-40, -40),
+    Score::new(-30, -30), Score::new(5, 5), Score::new(10, 10), Score::new(15, 15), Score::new(15, 15), Score::new(10, 10), Score::new(5, 5), Score::new(-30, -30),
+    Score::new(-30, -30), Score::new(0, 0), Score::new(15, 15), Score::new(20, 20), Score::new(20, 20), Score::new(15, 15), Score::new(0, 0), Score::new(-30, -30),
+    Score::new(-30, -30), Score::new(5, 5), Score::new(15, 15), Score::new(20, 20), Score::new(20, 20), Score::new(15, 15), Score::new(5, 5), Score::new(-30, -30),
+    Score::new(-30, -30), Score::new(0, 0), Score::new(10, 10), Score::new(15, 15), Score::new(15, 15), Score::new(10, 10), Score::new(0, 0), Score::new(-30, -30),
+    Score::new(-40, -40), Score::new(-20, -20), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-20, -20), Score::new(-40, -40),
+    Score::new(-50, -50), Score::new(-40, -40), Score::new(-30, -30), Score::new(-30, -30), Score::new(-30, -30), Score::new(-30, -30), Score::new(-40, -40), Score::new(-50, -50),
+    // Piece-square table: bishop, from a1 to h8
+    Score::new(-20, -20), Score::new(-10, -10), Score::new(-10, -10), Score::new(-10, -10), Score::new(-10, -10), Score::new(-10, -10), Score::new(-10, -10), Score::new(-20, -20),
+    Score::new(-10, -10), Score::new(5, 5), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(5, 5), Score::new(-10, -10),
+    Score::new(-10, -10), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(-10, -10),
+    Score::new(-10, -10), Score::new(0, 0), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(0, 0), Score::new(-10, -10),
+    Score::new(-10, -10), Score::new(5, 5), Score::new(5, 5), Score::new(10, 10), Score::new(10, 10), Score::new(5, 5), Score::new(5, 5), Score::new(-10, -10),
+    Score::new(-10, -10), Score::new(0, 0), Score::new(5, 5), Score::new(10, 10), Score::new(10, 10), Score::new(5, 5), Score::new(0, 0), Score::new(-10, -10),
+    Score::new(-10, -10), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-10, -10),
+    Score::new(-20, -20), Score::new(-10, -10), Score::new(-10, -10), Score::new(-10, -10), Score::new(-10, -10), Score::new(-10, -10), Score::new(-10, -10), Score::new(-20, -20),
+    // Piece-square table: rook, from a1 to h8
+    Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(5, 5), Score::new(5, 5), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0),
+    Score::new(-5, -5), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-5, -5),
+    Score::new(-5, -5), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-5, -5),
+    Score::new(-5, -5), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-5, -5),
+    Score::new(-5, -5), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-5, -5),
+    Score::new(-5, -5), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-5, -5),
+    Score::new(5, 5), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(10, 10), Score::new(5, 5),
+    Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0),
+    // Piece-square table: queen, from a1 to h8
+    Score::new(-20, -20), Score::new(-10, -10), Score::new(-10, -10), Score::new(-5, -5), Score::new(-5, -5), Score::new(-10, -10), Score::new(-10, -10), Score::new(-20, -20),
+    Score::new(-10, -10), Score::new(0, 0), Score::new(5, 5), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-10, -10),
+    Score::new(-10, -10), Score::new(5, 5), Score::new(5, 5), Score::new(5, 5), Score::new(5, 5), Score::new(5, 5), Score::new(0, 0), Score::new(-10, -10),
+    Score::new(0, 0), Score::new(0, 0), Score::new(5, 5), Score::new(5, 5), Score::new(5, 5), Score::new(5, 5), Score::new(0, 0), Score::new(-5, -5),
+    Score::new(-5, -5), Score::new(0, 0), Score::new(5, 5), Score::new(5, 5), Score::new(5, 5), Score::new(5, 5), Score::new(0, 0), Score::new(-5, -5),
+    Score::new(-10, -10), Score::new(0, 0), Score::new(5, 5), Score::new(5, 5), Score::new(5, 5), Score::new(5, 5), Score::new(0, 0), Score::new(-10, -10),
+    Score::new(-10, -10), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(-10, -10),
+    Score::new(-20, -20), Score::new(-10, -10), Score::new(-10, -10), Score::new(-5, -5), Score::new(-5, -5), Score::new(-10, -10), Score::new(-10, -10), Score::new(-20, -20),
+    // Piece-square table: king, from a1 to h8
+    Score::new(20, -50), Score::new(30, -30), Score::new(10, -30), Score::new(0, -30), Score::new(0, -30), Score::new(10, -30), Score::new(30, -30), Score::new(20, -50),
+    Score::new(20, -30), Score::new(20, -30), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(0, 0), Score::new(20, -30), Score::new(20, -30),
+    Score::new(-10, -30), Score::new(-20, -10), Score::new(-20, 20), Score::new(-20, 30), Score::new(-20, 30), Score::new(-20, 20), Score::new(-20, -10), Score::new(-10, -30),
+    Score::new(-20, -30), Score::new(-30, -10), Score::new(-30, 30), Score::new(-40, 40), Score::new(-40, 40), Score::new(-30, 30), Score::new(-30, -10), Score::new(-20, -30),
+    Score::new(-30, -30), Score::new(-40, -10), Score::new(-40, 30), Score::new(-50, 40), Score::new(-50, 40), Score::new(-40, 30), Score::new(-40, -10), Score::new(-30, -30),
+    Score::new(-30, -30), Score::new(-40, -10), Score::new(-40, 20), Score::new(-50, 30), Score::new(-50, 30), Score::new(-40, 20), Score::new(-40, -10), Score::new(-30, -30),
+    Score::new(-30, -30), Score::new(-40, -20), Score::new(-40, -10), Score::new(-50, 0), Score::new(-50, 0), Score::new(-40, -10), Score::new(-40, -20), Score::new(-30, -30),
+    Score::new(-30, -50), Score::new(-40, -40), Score::new(-40, -30), Score::new(-50, -20), Score::new(-50, -20), Score::new(-40, -30), Score::new(-40, -40), Score::new(-30, -50),
+    // Mobility per reachable square: pawn, knight, bishop, rook, queen, king
+    Score::new(0, 0), Score::new(4, 4), Score::new(5, 5), Score::new(2, 4), Score::new(1, 2), Score::new(0, 0),
+    // Doubled pawn
+    Score::new(-10, -20),
+    // Isolated pawn
+    Score::new(-10, -15),
+    // Passed pawn by relative rank, from 1 to 8
+    Score::new(0, 0), Score::new(5, 10), Score::new(5, 15), Score::new(10, 25), Score::new(20, 45), Score::new(35, 75), Score::new(60, 120), Score::new(0, 0),
+    // King shield pawn
+    Score::new(10, 0),
+    // Attack on a square next to the king
+    Score::new(-8, -2),
+    // Bishop pair
+    Score::new(30, 50),
+];
@@ -1,53 +1,751 @@
-use tokio::sync::watch::{self, Receiver, Sender};
+use std::{
+    sync::atomic::AtomicBool,
+    time::{Duration, Instant},
+};
+
+use tokio::{
+    sync::{
+        broadcast,
+        watch::{self, Receiver, Sender},
+    },
+    time,
+};
 
 use crate::{
-    board::{Board, Outcome},
+    board::{Board, DrawReason, Outcome},
+    clock::{Clock, TimeControl},
+    errors::InvalidMove,
+    half_move::HalfMove,
     piece::Color,
-    player::Player,
+    player::{Action, AsyncPlayer, Turn},
 };
 
-pub struct Game<W: Player, B: Player> {
+/// How many rejected actions in one turn the default [`IllegalMovePolicy`] forgives.
+const DEFAULT_RETRIES: u32 = 3;
+/// How many events a subscriber may fall behind before it starts missing them.
+const EVENT_CAPACITY: usize = 256;
+
+/// What happens when a player suggests an illegal move, or an action the game does not allow
+/// at that point, such as accepting a draw nobody offered.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IllegalMovePolicy {
+    /// Tell the player why the move was rejected and ask again, as often as it takes. A player
+    /// that keeps repeating the same mistake stalls an untimed game forever.
+    Retry,
+    /// Like [`IllegalMovePolicy::Retry`], but the player forfeits once this many moves in a
+    /// single turn have been rejected.
+    RetryUpTo(u32),
+    /// The first illegal move forfeits the game.
+    Forfeit,
+}
+
+/// A few retries, so a slip costs nothing but a player stuck on a bad move cannot stall the game.
+impl Default for IllegalMovePolicy {
+    fn default() -> Self {
+        Self::RetryUpTo(DEFAULT_RETRIES)
+    }
+}
+
+impl IllegalMovePolicy {
+    /// Whether a player whose last `rejected` suggestions this turn were illegal may try again.
+    const fn allows_retry(self, rejected: u32) -> bool {
+        match self {
+            Self::Retry => true,
+            Self::RetryUpTo(limit) => rejected < limit,
+            Self::Forfeit => false,
+        }
+    }
+}
+
+/// What watchers of a game see after every move.
+#[derive(Clone)]
+pub struct GameState {
+    pub board: Board,
+    /// Both sides' remaining time, if the game is timed.
+    pub clock: Option<Clock>,
+}
+
+/// Something that happened in a game, as sent to every [`Game::subscribe`]r.
+#[derive(Clone)]
+pub enum GameEvent {
+    /// The game is about to ask White for its first move.
+    Started {
+        board: Board,
+        clock: Option<Clock>,
+    },
+    /// `color` played `half_move`, written `san` in the position before it, leaving `board`
+    /// and `clock`.
+    MovePlayed {
+        color: Color,
+        half_move: HalfMove,
+        san: String,
+        board: Board,
+        clock: Option<Clock>,
+    },
+    /// The game rejected an action by `color`, such as an illegal move, for `reason`.
+    IllegalMove {
+        color: Color,
+        action: Action,
+        reason: String,
+    },
+    /// `color` offered a draw, which stands until its opponent has moved.
+    DrawOffered {
+        color: Color,
+    },
+    GameOver {
+        outcome: Outcome,
+    },
+}
+
+pub struct Game<W: AsyncPlayer, B: AsyncPlayer> {
     white: W,
     black: B,
     board: Board,
-    sender: Sender<Board>,
-    receiver: Receiver<Board>,
+    clock: Option<Clock>,
+    illegal_move_policy: IllegalMovePolicy,
+    /// The longest a player may think about one move, whatever its clock says.
+    move_timeout: Option<Duration>,
+    /// The side whose draw offer is standing, if any.
+    draw_offer: Option<Color>,
+    sender: Sender<GameState>,
+    receiver: Receiver<GameState>,
+    events: broadcast::Sender<GameEvent>,
 }
 
-impl<W: Player, B: Player> Game<W, B> {
-    pub fn view(&self) -> Receiver<Board> {
+impl<W: AsyncPlayer, B: AsyncPlayer> Game<W, B> {
+    pub fn view(&self) -> Receiver<GameState> {
         self.receiver.clone()
     }
 
+    /// Receives every event from now on. A subscriber that falls more than a few hundred events
+    /// behind misses the oldest and is told how many it lost.
+    pub fn subscribe(&self) -> broadcast::Receiver<GameEvent> {
+        self.events.subscribe()
+    }
+
     pub fn new(white: W, black: B) -> Self {
         let board = Board::new();
-        let (sender, receiver) = watch::channel(board);
+        let (sender, receiver) = watch::channel(GameState {
+            board: board.clone(),
+            clock: None,
+        });
         Self {
             white,
             black,
             board,
+            clock: None,
+            illegal_move_policy: IllegalMovePolicy::default(),
+            move_timeout: None,
+            draw_offer: None,
             sender,
             receiver,
+            events: broadcast::channel(EVENT_CAPACITY).0,
         }
     }
 
-    pub fn play(&mut self) -> Outcome {
-        loop {
+    /// Plays the game under `control`, starting both clocks from its base time.
+    #[must_use]
+    pub fn with_time_control(self, control: TimeControl) -> Self {
+        let game = Self {
+            clock: Some(Clock::new(control)),
+            ..self
+        };
+        game.publish();
+        game
+    }
+
+    #[must_use]
+    pub fn with_illegal_move_policy(self, policy: IllegalMovePolicy) -> Self {
+        Self {
+            illegal_move_policy: policy,
+            ..self
+        }
+    }
+
+    /// Limits every move to `timeout`. A player that thinks for longer loses as if its flag had
+    /// fallen, even in an untimed game.
+    #[must_use]
+    pub fn with_move_timeout(self, timeout: Duration) -> Self {
+        Self {
+            move_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Plays the game to its end. A player whose flag falls loses as soon as it does, without
+    /// waiting for its move. Dropping the future abandons the game where it stands, so it can be
+    /// cancelled or given an overall time limit with `tokio::select!` or `tokio::time::timeout`.
+    pub async fn play(&mut self) -> Outcome {
+        self.white.new_game();
+        self.black.new_game();
+        self.emit(GameEvent::Started {
+            board: self.board.clone(),
+            clock: self.clock,
+        });
+        let outcome = loop {
             if let Some(outcome) = self.board.get_outcome() {
-                return outcome;
+                break outcome;
             }
+            if let Some(outcome) = self.play_turn().await {
+                break outcome;
+            }
+            self.publish();
+        };
+        self.emit(GameEvent::GameOver { outcome });
+        outcome
+    }
 
-            let current_player: &dyn Player = match self.board.current_turn() {
-                Color::White => &self.white,
-                Color::Black => &self.black,
+    /// Asks the side to move for actions until it has moved, or the game has ended.
+    async fn play_turn(&mut self) -> Option<Outcome> {
+        let color = self.board.current_turn();
+        let opponent = color.opposite();
+        let mut draw_offered = self.draw_offer == Some(opponent);
+        let mut offered_this_turn = false;
+
+        // The clock keeps running while the player offers, declines and retries.
+        let started = Instant::now();
+        let mut rejected = 0;
+        let invalid = |reason: &str| {
+            Err(InvalidMove {
+                reason: reason.to_string(),
+            })
+        };
+        let half_move = loop {
+            // An async player learns that its turn is over by being dropped, so this stays down.
+            let stop = AtomicBool::new(false);
+            let turn = Turn {
+                board: &self.board,
+                clock: self.clock.as_ref(),
+                draw_offered,
+                stop: &stop,
+            };
+            let Some(action) = self.act(color, &turn, started).await else {
+                if let Some(clock) = &mut self.clock {
+                    clock.flag(color);
+                }
+                self.publish();
+                return Some(self.flag_fall(color));
             };
+            let result = match action {
+                Action::Move(half_move) => match self.board.validate_move(&half_move) {
+                    Ok(legal_move) => break legal_move,
+                    Err(reason) => Err(reason),
+                },
+                Action::Resign => return Some(Outcome::Resignation(opponent)),
+                Action::OfferDraw if offered_this_turn => {
+                    invalid("A draw was already offered this turn")
+                }
+                Action::OfferDraw => {
+                    offered_this_turn = true;
+                    self.emit(GameEvent::DrawOffered { color });
+                    Ok(())
+                }
+                Action::AcceptDraw if draw_offered => {
+                    return Some(Outcome::Draw(DrawReason::Agreement))
+                }
+                Action::DeclineDraw if draw_offered => {
+                    draw_offered = false;
+                    Ok(())
+                }
+                Action::AcceptDraw | Action::DeclineDraw => invalid("No draw offer is standing"),
+                Action::ClaimDraw => match self.board.claimable_draw() {
+                    Some(reason) => return Some(Outcome::Draw(reason)),
+                    None => invalid("No draw can be claimed in this position"),
+                },
+            };
+            if let Err(reason) = result {
+                self.emit(GameEvent::IllegalMove {
+                    color,
+                    action,
+                    reason: reason.reason.clone(),
+                });
+                rejected += 1;
+                if !self.illegal_move_policy.allows_retry(rejected) {
+                    return Some(Outcome::IllegalMove(opponent));
+                }
+                match color {
+                    Color::White => self.white.action_rejected(&action, &reason),
+                    Color::Black => self.black.action_rejected(&action, &reason),
+                }
+            }
+        };
+
+        if let Some(clock) = &mut self.clock {
+            if !clock.finish_move(color, started.elapsed()) {
+                self.publish();
+                return Some(self.flag_fall(color));
+            }
+        }
+        let san = self.board.to_san(&half_move);
+        self.board.make_move(&half_move);
+        self.draw_offer = offered_this_turn.then_some(color);
+        self.emit(GameEvent::MovePlayed {
+            color,
+            half_move,
+            san,
+            board: self.board.clone(),
+            clock: self.clock,
+        });
+        None
+    }
+
+    /// Asks `color` for an action, or returns `None` if it runs out of time first.
+    async fn act(&self, color: Color, turn: &Turn<'_>, started: Instant) -> Option<Action> {
+        let action = async {
+            match color {
+                Color::White => self.white.act(turn).await,
+                Color::Black => self.black.act(turn).await,
+            }
+        };
+        let on_clock = self.clock.map(|clock| clock.time_to_flag(color));
+        let allowed = match (on_clock, self.move_timeout) {
+            (Some(on_clock), Some(timeout)) => Some(on_clock.min(timeout)),
+            (on_clock, timeout) => on_clock.or(timeout),
+        };
+        match allowed {
+            Some(allowed) => time::timeout(allowed.saturating_sub(started.elapsed()), action)
+                .await
+                .ok(),
+            None => Some(action.await),
+        }
+    }
+
+    /// The outcome when `color` runs out of time: a loss, unless the opponent could not have
+    /// won anyway.
+    fn flag_fall(&self, color: Color) -> Outcome {
+        let opponent = color.opposite();
+        if self.board.has_mating_material(opponent) {
+            Outcome::Timeout(opponent)
+        } else {
+            Outcome::Draw(DrawReason::TimeoutVsInsufficientMaterial)
+        }
+    }
+
+    /// Sends `event` to the subscribers, if there are any.
+    fn emit(&self, event: GameEvent) {
+        let _ = self.events.send(event);
+    }
+
+    fn publish(&self) {
+        let _ = self.sender.send(GameState {
+            board: self.board.clone(),
+            clock: self.clock,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        future::{self, Future},
+        sync::{atomic::Ordering, Arc, Mutex},
+    };
+
+    use super::*;
+    use crate::{
+        player::{Blocking, Player},
+        search::{SearchLimits, SearchPlayer},
+    };
+
+    /// One action of a scripted player, decided from the turn it is asked in.
+    type Step = Box<dyn Fn(&Turn) -> Action + Send>;
+
+    /// Plays its steps in order, then resigns.
+    struct Scripted {
+        steps: Mutex<VecDeque<Step>>,
+        rejections: Mutex<Vec<String>>,
+    }
+
+    impl Scripted {
+        fn new(steps: impl IntoIterator<Item = Step>) -> Self {
+            Self {
+                steps: Mutex::new(steps.into_iter().collect()),
+                rejections: Mutex::default(),
+            }
+        }
+
+        fn rejections(&self) -> Vec<String> {
+            self.rejections.lock().unwrap().clone()
+        }
+    }
+
+    impl AsyncPlayer for Scripted {
+        fn act(&self, turn: &Turn) -> impl Future<Output = Action> + Send {
+            let step = self.steps.lock().unwrap().pop_front();
+            future::ready(step.map_or(Action::Resign, |step| step(turn)))
+        }
+
+        fn action_rejected(&self, _action: &Action, reason: &InvalidMove) {
+            self.rejections.lock().unwrap().push(reason.reason.clone());
+        }
+    }
+
+    fn san(san: &'static str) -> Step {
+        Box::new(move |turn| {
+            Action::Move(turn.board.parse_san(san).expect("scripted moves are legal"))
+        })
+    }
 
-            // TODO: do something with these returned values
-            let _ = self
-                .board
-                .move_piece(&current_player.suggest_move(&self.board));
+    fn just(action: Action) -> Step {
+        Box::new(move |_| action)
+    }
+
+    /// Thinks for `think` before every move, then plays the first legal one.
+    struct Slow {
+        think: Duration,
+    }
+
+    impl AsyncPlayer for Slow {
+        async fn act(&self, turn: &Turn<'_>) -> Action {
+            let half_move = turn.board.legal_moves()[0];
+            time::sleep(self.think).await;
+            Action::Move(half_move)
+        }
+    }
+
+    fn illegal() -> Step {
+        just(Action::Move("e2e5".parse().unwrap()))
+    }
+
+    /// Does the same thing every time it is asked.
+    struct Repeating(Action);
 
-            let _ = self.sender.send(self.board);
+    impl AsyncPlayer for Repeating {
+        fn act(&self, _turn: &Turn) -> impl Future<Output = Action> + Send {
+            future::ready(self.0)
         }
     }
+
+    const fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[tokio::test]
+    async fn flag_falls_while_the_player_thinks() {
+        let control = TimeControl::new(millis(100), Duration::ZERO);
+        let white = Slow {
+            think: Duration::from_secs(60),
+        };
+        let mut game = Game::new(white, Scripted::new([])).with_time_control(control);
+        let view = game.view();
+        let started = Instant::now();
+        assert_eq!(game.play().await, Outcome::Timeout(Color::Black));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        let clock = view.borrow().clock.expect("the game is timed");
+        assert_eq!(clock.remaining(Color::White), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn clocks_run_down_and_are_published() {
+        let control = TimeControl::new(Duration::from_secs(10), millis(500));
+        let white = Slow { think: millis(50) };
+        let black = Scripted::new([san("e5")]);
+        let mut game = Game::new(white, black).with_time_control(control);
+        let view = game.view();
+        assert_eq!(game.play().await, Outcome::Resignation(Color::White));
+        let clock = view.borrow().clock.expect("the game is timed");
+        // White moved twice, thinking for about 50 ms and getting 500 back each time.
+        let white = clock.remaining(Color::White);
+        assert!(
+            white > millis(10_500) && white < millis(10_900),
+            "{white:?}"
+        );
+        assert!(clock.remaining(Color::Black) > Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn flag_against_a_lone_king_is_a_draw() {
+        let control = TimeControl::new(millis(50), Duration::ZERO);
+        let white = Slow {
+            think: Duration::from_secs(60),
+        };
+        let mut game = Game::new(white, Scripted::new([])).with_time_control(control);
+        game.board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+        assert_eq!(
+            game.play().await,
+            Outcome::Draw(DrawReason::TimeoutVsInsufficientMaterial)
+        );
+    }
+
+    #[tokio::test]
+    async fn forfeit_ends_the_game_at_the_first_illegal_move() {
+        let mut game = Game::new(Scripted::new([illegal()]), Scripted::new([]))
+            .with_illegal_move_policy(IllegalMovePolicy::Forfeit);
+        assert_eq!(game.play().await, Outcome::IllegalMove(Color::Black));
+        assert_eq!(game.white.rejections(), Vec::<String>::new());
+    }
+
+    #[tokio::test]
+    async fn retry_up_to_forfeits_once_the_retries_run_out() {
+        let white = Scripted::new([illegal(), illegal(), illegal()]);
+        let mut game = Game::new(white, Scripted::new([]))
+            .with_illegal_move_policy(IllegalMovePolicy::RetryUpTo(2));
+        assert_eq!(game.play().await, Outcome::IllegalMove(Color::Black));
+        assert_eq!(game.white.rejections().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn retry_up_to_lets_the_player_recover() {
+        let white = Scripted::new([illegal(), illegal(), san("e4")]);
+        let mut game = Game::new(white, Scripted::new([]))
+            .with_illegal_move_policy(IllegalMovePolicy::RetryUpTo(3));
+        assert_eq!(game.play().await, Outcome::Resignation(Color::White));
+        assert_eq!(
+            game.white.rejections(),
+            ["Piece cannot move to position 'to'"; 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_asks_again_as_often_as_it_takes() {
+        let white = Scripted::new((0..20).map(|_| illegal()).chain([san("e4")]));
+        let mut game =
+            Game::new(white, Scripted::new([])).with_illegal_move_policy(IllegalMovePolicy::Retry);
+        assert_eq!(game.play().await, Outcome::Resignation(Color::White));
+        assert_eq!(game.white.rejections().len(), 20);
+    }
+
+    #[tokio::test]
+    async fn default_policy_does_not_let_a_stuck_player_stall_the_game() {
+        for action in [Action::OfferDraw, Action::Move("e2e5".parse().unwrap())] {
+            let mut game = Game::new(Repeating(action), Scripted::new([]));
+            let outcome = time::timeout(Duration::from_secs(5), game.play()).await;
+            assert_eq!(outcome.ok(), Some(Outcome::IllegalMove(Color::Black)));
+        }
+    }
+
+    /// Checks what the player is told about draw offers, then acts.
+    fn expecting_offer(offered: bool, action: Action) -> Step {
+        Box::new(move |turn| {
+            assert_eq!(turn.draw_offered, offered);
+            action
+        })
+    }
+
+    #[tokio::test]
+    async fn resigning_loses() {
+        let mut game = Game::new(
+            Scripted::new([san("e4")]),
+            Scripted::new([just(Action::Resign)]),
+        );
+        assert_eq!(game.play().await, Outcome::Resignation(Color::White));
+        let mut game = Game::new(Scripted::new([just(Action::Resign)]), Scripted::new([]));
+        assert_eq!(game.play().await, Outcome::Resignation(Color::Black));
+    }
+
+    #[tokio::test]
+    async fn accepted_offers_draw_the_game() {
+        let white = Scripted::new([just(Action::OfferDraw), san("e4")]);
+        let black = Scripted::new([expecting_offer(true, Action::AcceptDraw)]);
+        let mut game = Game::new(white, black);
+        assert_eq!(game.play().await, Outcome::Draw(DrawReason::Agreement));
+    }
+
+    #[tokio::test]
+    async fn declined_offers_go_on_with_the_turn() {
+        let white = Scripted::new([
+            just(Action::OfferDraw),
+            san("e4"),
+            expecting_offer(false, Action::Resign),
+        ]);
+        let black = Scripted::new([
+            expecting_offer(true, Action::DeclineDraw),
+            expecting_offer(false, Action::Move("e7e5".parse().unwrap())),
+        ]);
+        let mut game = Game::new(white, black);
+        assert_eq!(game.play().await, Outcome::Resignation(Color::Black));
+        assert_eq!(game.black.rejections(), Vec::<String>::new());
+    }
+
+    #[tokio::test]
+    async fn offers_lapse_once_the_opponent_moves() {
+        let white = Scripted::new([just(Action::OfferDraw), san("e4"), just(Action::AcceptDraw)]);
+        let black = Scripted::new([san("e5")]);
+        let mut game = Game::new(white, black).with_illegal_move_policy(IllegalMovePolicy::Forfeit);
+        assert_eq!(game.play().await, Outcome::IllegalMove(Color::Black));
+    }
+
+    #[tokio::test]
+    async fn invalid_draw_actions_are_rejected() {
+        let white = Scripted::new([
+            just(Action::AcceptDraw),
+            just(Action::DeclineDraw),
+            just(Action::ClaimDraw),
+            just(Action::OfferDraw),
+            just(Action::OfferDraw),
+            san("e4"),
+        ]);
+        let mut game =
+            Game::new(white, Scripted::new([])).with_illegal_move_policy(IllegalMovePolicy::Retry);
+        assert_eq!(game.play().await, Outcome::Resignation(Color::White));
+        assert_eq!(
+            game.white.rejections(),
+            [
+                "No draw offer is standing",
+                "No draw offer is standing",
+                "No draw can be claimed in this position",
+                "A draw was already offered this turn",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn threefold_repetition_can_be_claimed() {
+        let white = Scripted::new([
+            san("Nf3"),
+            san("Ng1"),
+            san("Nf3"),
+            san("Ng1"),
+            just(Action::ClaimDraw),
+        ]);
+        let black = Scripted::new([san("Nf6"), san("Ng8"), san("Nf6"), san("Ng8")]);
+        let mut game = Game::new(white, black);
+        assert_eq!(
+            game.play().await,
+            Outcome::Draw(DrawReason::ThreefoldRepetition)
+        );
+    }
+
+    /// Wraps a blocking player and notes when it gets back from thinking.
+    struct Watched<P> {
+        player: P,
+        returned: Arc<AtomicBool>,
+    }
+
+    impl<P: Player> Player for Watched<P> {
+        fn act(&self, turn: &Turn) -> Action {
+            let action = self.player.act(turn);
+            self.returned.store(true, Ordering::Relaxed);
+            action
+        }
+    }
+
+    /// A search with no limits of its own, which only ends when its turn is stopped.
+    fn endless_search() -> (Blocking<Watched<SearchPlayer>>, Arc<AtomicBool>) {
+        let returned = Arc::new(AtomicBool::new(false));
+        let player = Watched {
+            player: SearchPlayer::new(SearchLimits::default()),
+            returned: Arc::clone(&returned),
+        };
+        (Blocking::new(player), returned)
+    }
+
+    async fn wait_for(flag: &AtomicBool) -> bool {
+        for _ in 0..100 {
+            if flag.load(Ordering::Relaxed) {
+                return true;
+            }
+            time::sleep(millis(50)).await;
+        }
+        false
+    }
+
+    #[tokio::test]
+    async fn move_timeout_stops_a_blocking_player() {
+        let (white, returned) = endless_search();
+        let mut game = Game::new(white, Scripted::new([])).with_move_timeout(millis(200));
+        assert_eq!(game.play().await, Outcome::Timeout(Color::Black));
+        assert!(wait_for(&returned).await, "the search kept running");
+    }
+
+    #[tokio::test]
+    async fn abandoning_the_game_stops_a_blocking_player() {
+        let (white, returned) = endless_search();
+        let mut game = Game::new(white, Scripted::new([]));
+        assert!(time::timeout(millis(200), game.play()).await.is_err());
+        assert!(wait_for(&returned).await, "the search kept running");
+    }
+
+    /// Everything already sent to `events`, written out so the order is easy to compare.
+    fn drain(events: &mut broadcast::Receiver<GameEvent>) -> Vec<String> {
+        let mut described = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            described.push(match event {
+                GameEvent::Started { board, .. } => format!("started {}", board.to_fen()),
+                GameEvent::MovePlayed { color, san, .. } => format!("{color:?} played {san}"),
+                GameEvent::IllegalMove { color, reason, .. } => {
+                    format!("{color:?} illegal: {reason}")
+                }
+                GameEvent::DrawOffered { color } => format!("{color:?} offered a draw"),
+                GameEvent::GameOver { outcome } => format!("over: {outcome:?}"),
+            });
+        }
+        described
+    }
+
+    #[tokio::test]
+    async fn events_arrive_in_order_for_every_subscriber() {
+        let white = Scripted::new([illegal(), just(Action::OfferDraw), san("e4"), san("Qh5")]);
+        let black = Scripted::new([just(Action::DeclineDraw), san("e5")]);
+        let mut game = Game::new(white, black);
+        let (mut first, mut second) = (game.subscribe(), game.subscribe());
+        assert_eq!(game.play().await, Outcome::Resignation(Color::White));
+
+        let expected = [
+            format!("started {}", Board::new().to_fen()),
+            "White illegal: Piece cannot move to position 'to'".to_string(),
+            "White offered a draw".to_string(),
+            "White played e4".to_string(),
+            "Black played e5".to_string(),
+            "White played Qh5".to_string(),
+            "over: Resignation(White)".to_string(),
+        ];
+        assert_eq!(drain(&mut first), expected);
+        assert_eq!(drain(&mut second), expected);
+    }
+
+    #[tokio::test]
+    async fn moves_carry_the_position_and_clocks_after_them() {
+        let control = TimeControl::new(Duration::from_secs(10), Duration::from_secs(1));
+        let mut game =
+            Game::new(Scripted::new([san("Nf3")]), Scripted::new([])).with_time_control(control);
+        let mut events = game.subscribe();
+        game.play().await;
+
+        let Ok(GameEvent::Started { clock, .. }) = events.try_recv() else {
+            panic!("the game did not start with a Started event");
+        };
+        assert_eq!(
+            clock.map(|clock| clock.remaining(Color::White)),
+            Some(Duration::from_secs(10))
+        );
+        let Ok(GameEvent::MovePlayed {
+            color,
+            half_move,
+            san,
+            board,
+            clock,
+        }) = events.try_recv()
+        else {
+            panic!("the first move was not reported");
+        };
+        assert_eq!(color, Color::White);
+        assert_eq!(half_move.to_string(), "g1f3");
+        assert_eq!(san, "Nf3");
+        assert_eq!(
+            board.to_fen(),
+            "rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKB1R b KQkq - 1 1"
+        );
+        assert!(
+            clock.expect("the game is timed").remaining(Color::White) > Duration::from_secs(10)
+        );
+    }
+
+    #[tokio::test]
+    async fn flag_fall_ends_the_event_stream() {
+        let control = TimeControl::new(millis(50), Duration::ZERO);
+        let white = Slow {
+            think: Duration::from_secs(60),
+        };
+        let mut game = Game::new(white, Scripted::new([])).with_time_control(control);
+        let mut events = game.subscribe();
+        game.play().await;
+        let described = drain(&mut events);
+        assert_eq!(
+            described.last().map(String::as_str),
+            Some("over: Timeout(Black)")
+        );
+        assert_eq!(described.len(), 2);
+    }
 }
@@ -2,73 +2,163 @@
 #![feature(iter_intersperse)]
 #![allow(dead_code)]
 
-use std::array;
+use std::{array, env, fmt::Write, fs};
 
 use bitboard::Bitboard;
 use errors::PositionOutOfBounds;
+use magic::{BISHOP_DIRECTIONS, ROOK_DIRECTIONS};
 use position::{Axis, Position};
 
 mod bitboard;
 mod board;
+mod clock;
 mod errors;
+mod eval;
 mod game;
 mod half_move;
+mod magic;
+mod nnue;
 mod piece;
 mod piece_map;
 mod pieces;
 mod player;
 mod position;
+mod search;
+#[rustfmt::skip]
+mod tables;
+mod zobrist;
 
+const KNIGHT_OFFSETS: [(i32, i32); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+const KING_OFFSETS: [(i32, i32); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+
+const WHITE_PAWN_OFFSETS: [(i32, i32); 2] = [(1, -1), (1, 1)];
+const BLACK_PAWN_OFFSETS: [(i32, i32); 2] = [(-1, -1), (-1, 1)];
+
+/// Writes the precomputed tables to `src/tables.rs`, or to the path given as the first argument.
 fn main() {
-    let knight_moves: [Bitboard; 64] = array::from_fn(|i| {
-        let mut bitboard = Bitboard::new();
-        for offset in [
-            (1, 2),
-            (2, 1),
-            (2, -1),
-            (1, -2),
-            (-1, -2),
-            (-2, -1),
-            (-2, 1),
-            (-1, 2),
-        ] {
-            let _ = add_to_bitboard_at_offset(
-                Position::try_from(i).unwrap(),
-                offset.0,
-                offset.1,
-                &mut bitboard,
-            );
-        }
-        bitboard
-    });
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/src/tables.rs").to_string());
+
+    let knight_moves = offset_table(&KNIGHT_OFFSETS);
+    let king_moves = offset_table(&KING_OFFSETS);
+    let pawn_attacks = [
+        offset_table(&WHITE_PAWN_OFFSETS),
+        offset_table(&BLACK_PAWN_OFFSETS),
+    ];
+    let between: [[Bitboard; 64]; 64] =
+        array::from_fn(|a| array::from_fn(|b| between(square(a), square(b))));
+    let line: [[Bitboard; 64]; 64] =
+        array::from_fn(|a| array::from_fn(|b| line(square(a), square(b))));
+    let rook_magics = magic::find_magics(&ROOK_DIRECTIONS).map(Bitboard::from_data);
+    let bishop_magics = magic::find_magics(&BISHOP_DIRECTIONS).map(Bitboard::from_data);
+
+    let mut out = String::new();
+    out.push_str(
+        "//! Precomputed attack tables. Generated by `cargo run --bin gen_bitboards`, do not edit \
+         by hand.\n#![allow(clippy::unreadable_literal)]\n",
+    );
+    write_table(&mut out, "KNIGHT_ATTACKS", &[knight_moves]);
+    write_table(&mut out, "KING_ATTACKS", &[king_moves]);
+    write_table(&mut out, "PAWN_ATTACKS", &pawn_attacks);
+    write_table(&mut out, "BETWEEN", &between);
+    write_table(&mut out, "LINE", &line);
+    write_table(&mut out, "ROOK_MAGICS", &[rook_magics]);
+    write_table(&mut out, "BISHOP_MAGICS", &[bishop_magics]);
+
+    fs::write(&path, out).expect("failed to write tables");
+    println!("Wrote tables to {path}");
+}
+
+fn square(index: usize) -> Position {
+    Position::try_from(index).expect("index is below 64")
+}
 
-    let king_moves: [Bitboard; 64] = array::from_fn(|i| {
+fn offset_table(offsets: &[(i32, i32)]) -> [Bitboard; 64] {
+    array::from_fn(|i| {
         let mut bitboard = Bitboard::new();
-        for offset in [
-            (0, 1),
-            (1, 1),
-            (1, 0),
-            (1, -1),
-            (0, -1),
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-        ] {
-            let _ = add_to_bitboard_at_offset(
-                Position::try_from(i).unwrap(),
-                offset.0,
-                offset.1,
-                &mut bitboard,
-            );
+        for offset in offsets {
+            let _ = add_to_bitboard_at_offset(square(i), offset.0, offset.1, &mut bitboard);
         }
         bitboard
-    });
+    })
+}
 
-    println!(
-        "Knight: {:b}\n King: {:b}",
-        knight_moves[Position::new(Axis::H, Axis::H).index()].data(),
-        king_moves[Position::new(Axis::H, Axis::H).index()].data()
-    );
+/// The directions a slider would need to travel along to get from `a` to `b`, if they share a
+/// rank, file or diagonal.
+fn shared_directions(a: Position, b: Position) -> Option<&'static [(i32, i32)]> {
+    if a == b {
+        return None;
+    }
+    if magic::sliding_attacks(a, Bitboard::new(), &ROOK_DIRECTIONS).get(b) {
+        Some(&ROOK_DIRECTIONS)
+    } else if magic::sliding_attacks(a, Bitboard::new(), &BISHOP_DIRECTIONS).get(b) {
+        Some(&BISHOP_DIRECTIONS)
+    } else {
+        None
+    }
+}
+
+/// The squares strictly between `a` and `b`, or nothing if they are not aligned.
+fn between(a: Position, b: Position) -> Bitboard {
+    shared_directions(a, b).map_or_else(Bitboard::new, |directions| {
+        magic::sliding_attacks(a, Bitboard::from(b), directions)
+            & magic::sliding_attacks(b, Bitboard::from(a), directions)
+    })
+}
+
+/// The whole rank, file or diagonal through `a` and `b`, or nothing if they are not aligned.
+fn line(a: Position, b: Position) -> Bitboard {
+    shared_directions(a, b).map_or_else(Bitboard::new, |directions| {
+        let through_a = magic::sliding_attacks(a, Bitboard::new(), directions) | a.into();
+        let through_b = magic::sliding_attacks(b, Bitboard::new(), directions) | b.into();
+        through_a & through_b
+    })
+}
+
+fn write_table<const N: usize>(out: &mut String, name: &str, rows: &[[Bitboard; N]]) {
+    let (ty, indent) = if rows.len() == 1 {
+        (format!("[u64; {N}]"), "")
+    } else {
+        (format!("[[u64; {N}]; {}]", rows.len()), "    ")
+    };
+    writeln!(out, "\npub static {name}: {ty} = [").unwrap();
+    for row in rows {
+        if rows.len() > 1 {
+            writeln!(out, "    [").unwrap();
+        }
+        for chunk in row.chunks(8) {
+            let values: String = chunk
+                .iter()
+                .map(|bitboard| format!("{:#x},", bitboard.data()))
+                .intersperse(" ".to_string())
+                .collect();
+            writeln!(out, "{indent}    {values}").unwrap();
+        }
+        if rows.len() > 1 {
+            writeln!(out, "    ],").unwrap();
+        }
+    }
+    writeln!(out, "];").unwrap();
 }
 
 fn add_to_bitboard_at_offset(
@@ -85,7 +175,6 @@ fn add_to_bitboard_at_offset(
         .map_err(|_| PositionOutOfBounds)?;
     let new_rank = Axis::from_repr(new_x).ok_or(PositionOutOfBounds)?;
     let new_file = Axis::from_repr(new_y).ok_or(PositionOutOfBounds)?;
-    println!("{src:#?}, {new_rank:#?}, {new_file:#?}");
     bitboard.set(Position::new(new_rank, new_file));
     Ok(())
 }
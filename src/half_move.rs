@@ -1,13 +1,60 @@
-use crate::position::Position;
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
 
+use crate::{
+    board::BoardSide,
+    errors::InvalidNotation,
+    pieces::{self, PieceType, KING, PAWN},
+    position::Position,
+};
+
+/// What a move does beyond moving a piece from one square to another.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MoveKind {
+    Normal,
+    /// A pawn advancing two squares, leaving an en passant target behind it.
+    DoublePawnPush,
+    /// A pawn capturing the pawn that just passed it; the captured pawn is not on `to`.
+    EnPassant,
+    /// The king moving two squares towards a rook, which jumps over it.
+    Castle(BoardSide),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct HalfMove {
     pub from: Position,
     pub to: Position,
+    pub promotion: Option<&'static PieceType>,
+    pub kind: MoveKind,
 }
 
 impl HalfMove {
     pub const fn new(from: Position, to: Position) -> Self {
-        Self { from, to }
+        Self::with_kind(from, to, MoveKind::Normal)
+    }
+
+    pub const fn with_promotion(
+        from: Position,
+        to: Position,
+        promotion: &'static PieceType,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            promotion: Some(promotion),
+            kind: MoveKind::Normal,
+        }
+    }
+
+    pub const fn with_kind(from: Position, to: Position, kind: MoveKind) -> Self {
+        Self {
+            from,
+            to,
+            promotion: None,
+            kind,
+        }
     }
 
     pub const fn from(&self) -> &Position {
@@ -17,4 +64,139 @@ impl HalfMove {
     pub const fn to(&self) -> &Position {
         &self.to
     }
+
+    pub const fn promotion(&self) -> Option<&'static PieceType> {
+        self.promotion
+    }
+
+    pub const fn kind(&self) -> MoveKind {
+        self.kind
+    }
+
+    /// Whether `self` and `other` are the same squares and promotion, ignoring [`MoveKind`]. Moves
+    /// parsed from text have no kind until they are matched against a position.
+    pub fn same_squares(&self, other: &Self) -> bool {
+        self.from == other.from && self.to == other.to && self.promotion == other.promotion
+    }
+
+    /// Formats the move in UCI long algebraic notation. With `chess960` set, castling is written
+    /// as the king capturing its own rook, as Chess960 GUIs expect.
+    pub fn to_uci(self, chess960: bool) -> String {
+        let to = match self.kind {
+            MoveKind::Castle(side) if chess960 => {
+                Position::new(*self.from.rank(), side.rook_file())
+            }
+            _ => self.to,
+        };
+        let mut out = format!("{}{to}", self.from);
+        if let Some(promotion) = self.promotion {
+            out.push(promotion.piece_char());
+        }
+        out
+    }
+}
+
+impl Display for HalfMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_uci(false))
+    }
+}
+
+/// Shows the move in UCI notation, which reads better in test failures than its fields.
+impl Debug for HalfMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Parses UCI long algebraic notation such as `e2e4` or `e7e8q`. The result is always a
+/// [`MoveKind::Normal`] move; [`Board::parse_uci`](crate::board::Board::parse_uci) resolves it
+/// against a position.
+impl FromStr for HalfMove {
+    type Err = InvalidNotation;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidNotation {
+            reason: format!("'{string}' is not a UCI move"),
+        };
+        if !(4..=5).contains(&string.len()) || !string.is_ascii() {
+            return Err(invalid());
+        }
+
+        let from = string[0..2].parse().map_err(|_| invalid())?;
+        let to = string[2..4].parse().map_err(|_| invalid())?;
+        string[4..].chars().next().map_or_else(
+            || Ok(Self::new(from, to)),
+            |character| match pieces::get_piece_type_from_char(character) {
+                Some(ptype)
+                    if character.is_ascii_lowercase() && *ptype != PAWN && *ptype != KING =>
+                {
+                    Ok(Self::with_promotion(from, to, ptype))
+                }
+                _ => Err(invalid()),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pieces::{KNIGHT, QUEEN};
+
+    fn square(name: &str) -> Position {
+        name.parse().expect("test squares are valid")
+    }
+
+    #[test]
+    fn parses_plain_moves_and_promotions() {
+        assert_eq!(
+            "e2e4".parse::<HalfMove>().unwrap(),
+            HalfMove::new(square("e2"), square("e4"))
+        );
+        assert_eq!(
+            "e7e8q".parse::<HalfMove>().unwrap(),
+            HalfMove::with_promotion(square("e7"), square("e8"), &QUEEN)
+        );
+        assert_eq!(
+            "b2a1n".parse::<HalfMove>().unwrap(),
+            HalfMove::with_promotion(square("b2"), square("a1"), &KNIGHT)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_moves() {
+        for uci in [
+            "", "e2", "e2e", "e2e9", "i2e4", "e2e4e", "e7e8k", "e7e8p", "e7e8Q", "e2e4qq", "e2–e4",
+        ] {
+            assert!(uci.parse::<HalfMove>().is_err(), "'{uci}' parsed");
+        }
+    }
+
+    #[test]
+    fn formats_what_it_parses() {
+        for uci in ["e2e4", "a7a8q", "h2h1r", "c7b8b", "g1f3"] {
+            let half_move: HalfMove = uci.parse().unwrap();
+            assert_eq!(half_move.to_string(), uci);
+            assert_eq!(half_move.to_uci(true), uci);
+        }
+    }
+
+    #[test]
+    fn writes_castling_as_king_takes_rook_for_chess960() {
+        let king_side = HalfMove::with_kind(
+            square("e1"),
+            square("g1"),
+            MoveKind::Castle(BoardSide::KingSide),
+        );
+        let queen_side = HalfMove::with_kind(
+            square("e8"),
+            square("c8"),
+            MoveKind::Castle(BoardSide::QueenSide),
+        );
+        assert_eq!(king_side.to_uci(false), "e1g1");
+        assert_eq!(king_side.to_uci(true), "e1h1");
+        assert_eq!(queen_side.to_uci(false), "e8c8");
+        assert_eq!(queen_side.to_uci(true), "e8a8");
+    }
 }
@@ -0,0 +1,222 @@
+use std::sync::LazyLock;
+
+use crate::{bitboard::Bitboard, position::Position, tables};
+
+pub const ROOK_DIRECTIONS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+pub const BISHOP_DIRECTIONS: [(i32, i32); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// Per-rank seeds known to find magics for every square after only a few attempts.
+const SEEDS: [u64; 8] = [728, 10316, 55013, 32803, 12281, 15100, 16645, 255];
+
+static TABLES: LazyLock<SliderTables> = LazyLock::new(SliderTables::new);
+
+pub fn rook_attacks(position: Position, occupied: Bitboard) -> Bitboard {
+    TABLES.attacks(&TABLES.rook[position.index()], occupied)
+}
+
+pub fn bishop_attacks(position: Position, occupied: Bitboard) -> Bitboard {
+    TABLES.attacks(&TABLES.bishop[position.index()], occupied)
+}
+
+pub fn queen_attacks(position: Position, occupied: Bitboard) -> Bitboard {
+    rook_attacks(position, occupied) | bishop_attacks(position, occupied)
+}
+
+/// Searches for a collision-free multiplier for every square. Only `gen_bitboards` calls this;
+/// the results are checked in to `tables.rs`.
+pub fn find_magics(directions: &[(i32, i32)]) -> [u64; 64] {
+    let mut out = [0; 64];
+    for (index, position) in Bitboard::from_data(u64::MAX).into_iter().enumerate() {
+        let mut rng = Xorshift::new(SEEDS[*position.rank() as usize]);
+        let (mask, subsets) = occupancy_subsets(position, directions);
+        out[index] = loop {
+            let multiplier = rng.sparse();
+            if (mask.data().wrapping_mul(multiplier) >> 56).count_ones() < 6 {
+                continue;
+            }
+            if place(mask, multiplier, &subsets, &mut Vec::new()).is_some() {
+                break multiplier;
+            }
+        };
+    }
+    out
+}
+
+#[derive(Clone, Copy, Default)]
+struct Magic {
+    mask: u64,
+    multiplier: u64,
+    shift: u32,
+    offset: usize,
+}
+
+impl Magic {
+    // The shift leaves at most 12 significant bits, so the cast cannot truncate.
+    #[allow(clippy::cast_possible_truncation)]
+    const fn index(&self, occupied: Bitboard) -> usize {
+        ((occupied.data() & self.mask).wrapping_mul(self.multiplier) >> self.shift) as usize
+            + self.offset
+    }
+}
+
+struct SliderTables {
+    rook: [Magic; 64],
+    bishop: [Magic; 64],
+    attacks: Vec<Bitboard>,
+}
+
+impl SliderTables {
+    fn attacks(&self, magic: &Magic, occupied: Bitboard) -> Bitboard {
+        self.attacks[magic.index(occupied)]
+    }
+
+    fn new() -> Self {
+        let mut attacks = Vec::new();
+        let mut rook = [Magic::default(); 64];
+        let mut bishop = [Magic::default(); 64];
+        for (index, position) in Bitboard::from_data(u64::MAX).into_iter().enumerate() {
+            rook[index] = build(
+                position,
+                &ROOK_DIRECTIONS,
+                tables::ROOK_MAGICS[index],
+                &mut attacks,
+            );
+            bishop[index] = build(
+                position,
+                &BISHOP_DIRECTIONS,
+                tables::BISHOP_MAGICS[index],
+                &mut attacks,
+            );
+        }
+        Self {
+            rook,
+            bishop,
+            attacks,
+        }
+    }
+}
+
+fn build(
+    position: Position,
+    directions: &[(i32, i32)],
+    multiplier: u64,
+    attacks: &mut Vec<Bitboard>,
+) -> Magic {
+    let (mask, subsets) = occupancy_subsets(position, directions);
+    place(mask, multiplier, &subsets, attacks)
+        .expect("magic in tables.rs collides, rerun gen_bitboards")
+}
+
+/// Walks each ray from `position` until it leaves the board or hits an occupied square, which is
+/// included in the result.
+pub fn sliding_attacks(
+    position: Position,
+    occupied: Bitboard,
+    directions: &[(i32, i32)],
+) -> Bitboard {
+    let mut out = Bitboard::new();
+    for &(rank_offset, file_offset) in directions {
+        let mut current = position;
+        while let Some(next) = current.offset(rank_offset, file_offset) {
+            out.set(next);
+            if occupied.get(next) {
+                break;
+            }
+            current = next;
+        }
+    }
+    out
+}
+
+/// The squares whose occupancy can affect a slider's attacks: every ray square except the last
+/// one before the edge of the board.
+fn relevant_mask(position: Position, directions: &[(i32, i32)]) -> Bitboard {
+    let mut out = Bitboard::new();
+    for &(rank_offset, file_offset) in directions {
+        let mut current = position;
+        while let Some(next) = current.offset(rank_offset, file_offset) {
+            if next.offset(rank_offset, file_offset).is_none() {
+                break;
+            }
+            out.set(next);
+            current = next;
+        }
+    }
+    out
+}
+
+/// Pairs every subset of the relevant mask with the attacks a slider has given that occupancy.
+fn occupancy_subsets(
+    position: Position,
+    directions: &[(i32, i32)],
+) -> (Bitboard, Vec<(Bitboard, Bitboard)>) {
+    let mask = relevant_mask(position, directions);
+    let mut subsets = Vec::with_capacity(1 << mask.count());
+
+    // Enumerate every subset of the mask using the Carry-Rippler trick.
+    let mut subset = 0u64;
+    loop {
+        let occupied = Bitboard::from_data(subset);
+        subsets.push((occupied, sliding_attacks(position, occupied, directions)));
+        subset = subset.wrapping_sub(mask.data()) & mask.data();
+        if subset == 0 {
+            break;
+        }
+    }
+    (mask, subsets)
+}
+
+/// Appends the attack table for `multiplier` to `attacks`, or returns `None` without touching
+/// `attacks` if two occupancies with different attacks share an index.
+fn place(
+    mask: Bitboard,
+    multiplier: u64,
+    subsets: &[(Bitboard, Bitboard)],
+    attacks: &mut Vec<Bitboard>,
+) -> Option<Magic> {
+    let magic = Magic {
+        mask: mask.data(),
+        multiplier,
+        shift: 64 - mask.count(),
+        offset: attacks.len(),
+    };
+    let mut table = vec![None; subsets.len()];
+    for &(occupied, attack) in subsets {
+        let slot = &mut table[magic.index(occupied) - magic.offset];
+        match slot {
+            Some(existing) if *existing != attack => return None,
+            _ => *slot = Some(attack),
+        }
+    }
+    attacks.extend(table.into_iter().map(Option::unwrap_or_default));
+    Some(magic)
+}
+
+/// A small, seedable pseudo-random generator, for reproducible magic searches and random games.
+pub struct Xorshift {
+    state: u64,
+}
+
+impl Xorshift {
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub const fn next(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    const fn sparse(&mut self) -> u64 {
+        self.next() & self.next() & self.next()
+    }
+
+    /// Picks one of `items` at random, or `None` if there are none.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        let len = u64::try_from(items.len()).ok()?;
+        let index = usize::try_from(self.next().checked_rem(len)?).ok()?;
+        items.get(index)
+    }
+}
@@ -2,47 +2,90 @@
 #![feature(iter_intersperse)]
 #![allow(dead_code)]
 
-use std::{thread, time::Duration};
+use std::time::Duration;
 
-use game::Game;
-use player::Player;
-use position::{Axis, Position};
+use clock::{Clock, TimeControl};
+use game::{Game, GameEvent};
+use piece::Color;
+use player::Blocking;
+use search::{SearchLimits, SearchPlayer};
+use tokio::sync::broadcast::error::RecvError;
 
 mod bitboard;
 mod board;
+mod clock;
 mod errors;
+mod eval;
 mod game;
 mod half_move;
+mod magic;
+mod nnue;
 mod piece;
 mod piece_map;
 mod pieces;
 mod player;
 mod position;
+mod search;
+#[rustfmt::skip]
+mod tables;
+mod zobrist;
 
-fn main() {
-    let player1 = TestPlayer {};
-    let player2 = TestPlayer {};
-    let mut game = Game::new(player1, player2);
-    let board_view = game.view();
-    thread::spawn(move || {
-        game.play();
+#[tokio::main]
+async fn main() {
+    let control = TimeControl::new(Duration::from_secs(60), Duration::from_secs(1));
+    let player1 = Blocking::new(SearchPlayer::new(SearchLimits::default()));
+    let player2 = Blocking::new(SearchPlayer::new(SearchLimits::default()));
+    let mut game = Game::new(player1, player2).with_time_control(control);
+    let mut events = game.subscribe();
+    tokio::spawn(async move {
+        game.play().await;
     });
 
     loop {
-        println!("{}\n", *board_view.borrow());
-        thread::sleep(Duration::from_millis(200));
+        match events.recv().await {
+            Ok(GameEvent::Started { board, clock }) => {
+                println!("{board}");
+                print_clock(clock.as_ref());
+            }
+            Ok(GameEvent::MovePlayed {
+                color,
+                san,
+                board,
+                clock,
+                ..
+            }) => {
+                println!("{color:?} plays {san}");
+                println!("{board}");
+                print_clock(clock.as_ref());
+            }
+            Ok(GameEvent::IllegalMove { color, reason, .. }) => {
+                println!("{color:?} tried an illegal move: {reason}");
+            }
+            Ok(GameEvent::DrawOffered { color }) => println!("{color:?} offers a draw"),
+            Ok(GameEvent::GameOver { outcome }) => {
+                println!("Game over: {outcome:?}");
+                break;
+            }
+            // Missing a few boards only matters to the display, which catches up on the next.
+            Err(RecvError::Lagged(_)) => {}
+            Err(RecvError::Closed) => break,
+        }
     }
 }
 
-struct TestPlayer {}
-
-impl Player for TestPlayer {
-    fn suggest_move(&self, board: &board::Board) -> half_move::HalfMove {
-        _ = board;
-        thread::sleep(Duration::from_secs(5));
-        half_move::HalfMove::new(
-            Position::new(Axis::A, Axis::A),
-            Position::new(Axis::B, Axis::B),
-        )
+fn print_clock(clock: Option<&Clock>) {
+    if let Some(clock) = clock {
+        println!(
+            "White {}  Black {}",
+            format_time(clock.remaining(Color::White)),
+            format_time(clock.remaining(Color::Black))
+        );
     }
+    println!();
+}
+
+/// Formats a clock reading as minutes, seconds and tenths.
+fn format_time(time: Duration) -> String {
+    let tenths = time.as_millis() / 100;
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
 }
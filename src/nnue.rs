@@ -0,0 +1,515 @@
+use std::{fs, path::Path};
+
+use enum_map::EnumMap;
+use strum::IntoEnumIterator;
+
+use crate::{
+    board::{Board, UndoInfo},
+    errors::InvalidNetwork,
+    half_move::{HalfMove, MoveKind},
+    piece::{Color, Piece},
+    pieces::{PAWN, PIECE_TYPES, ROOK},
+    position::Position,
+    search::{MATE, MAX_PLY},
+};
+
+mod simd;
+
+/// Identifies a network file, followed by a little-endian `u32` format version.
+const MAGIC: &[u8; 4] = b"CHNN";
+const VERSION: u32 = 1;
+/// The king's half of the board, towards the queen or king side, and whether it has left its
+/// back rank, pick one of four sets of piece-square inputs.
+const KING_BUCKETS: usize = 4;
+/// Inputs per king bucket: every piece type of either color, own pieces first, on every square.
+const BUCKET_INPUTS: usize = 12 * 64;
+pub const INPUTS: usize = KING_BUCKETS * BUCKET_INPUTS;
+/// The largest hidden activation after clipping, matching the trainer's quantization.
+const QA: i16 = 255;
+/// The quantization factor of the output weights.
+const QB: i32 = 64;
+/// Converts the network's output to centipawns.
+const SCALE: i64 = 400;
+/// The largest score the network may give, so that no evaluation reads as a forced mate.
+const MAX_SCORE: i32 = MATE - MAX_PLY.cast_signed() - 1;
+
+/// A quantized HalfKA-style network: each side sees the board from its own perspective,
+/// through a hidden layer chosen by where its king stands, and both hidden layers feed a single
+/// output.
+///
+/// Network files start with [`MAGIC`], the format version and the hidden layer size as
+/// little-endian `u32`s, followed by the little-endian `i16` input weights (one row of hidden
+/// weights per input), the `i16` hidden biases, the `i16` output weights for the side to move
+/// and then the other side, and the `i32` output bias.
+pub struct Network {
+    hidden: usize,
+    input_weights: Box<[i16]>,
+    hidden_biases: Box<[i16]>,
+    output_weights: Box<[i16]>,
+    output_bias: i32,
+}
+
+impl Network {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, InvalidNetwork> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|error| InvalidNetwork {
+            reason: format!("cannot read {}: {error}", path.display()),
+        })?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidNetwork> {
+        let invalid = |reason: String| Err(InvalidNetwork { reason });
+        let Some((header, body)) = bytes.split_at_checked(12) else {
+            return invalid("the file is too short for a header".to_string());
+        };
+        if &header[..4] != MAGIC {
+            return invalid("the file is not a network".to_string());
+        }
+        let version = u32::from_le_bytes(header[4..8].try_into().expect("four bytes"));
+        if version != VERSION {
+            return invalid(format!("unsupported format version {version}"));
+        }
+        let hidden = u32::from_le_bytes(header[8..12].try_into().expect("four bytes")) as usize;
+        if hidden == 0 {
+            return invalid("the hidden layer is empty".to_string());
+        }
+
+        let weights = (INPUTS + 3) * hidden;
+        let expected = weights * 2 + 4;
+        if body.len() != expected {
+            return invalid(format!(
+                "expected {expected} bytes of weights for {hidden} hidden neurons but found {}",
+                body.len()
+            ));
+        }
+        let (weights, output_bias) = body.split_at(weights * 2);
+        let mut weights: Vec<i16> = weights
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|pair| i16::from_le_bytes(*pair))
+            .collect();
+        let output_weights = weights.split_off((INPUTS + 1) * hidden);
+        let hidden_biases = weights.split_off(INPUTS * hidden);
+        Ok(Self {
+            hidden,
+            input_weights: weights.into(),
+            hidden_biases: hidden_biases.into(),
+            output_weights: output_weights.into(),
+            output_bias: i32::from_le_bytes(output_bias.try_into().expect("four bytes")),
+        })
+    }
+
+    /// The hidden layer for `perspective` computed from scratch.
+    fn refresh(&self, board: &Board, perspective: Color) -> Box<[i16]> {
+        let mut accumulator = self.hidden_biases.clone();
+        let Some(king) = board.king_position(perspective) else {
+            return accumulator;
+        };
+        for color in Color::iter() {
+            for ptype in PIECE_TYPES {
+                let piece = Piece::new(ptype, color);
+                for position in board.pieces(&piece) {
+                    simd::add(
+                        &mut accumulator,
+                        self.weights(feature(perspective, king, &piece, position)),
+                    );
+                }
+            }
+        }
+        accumulator
+    }
+
+    fn weights(&self, feature: usize) -> &[i16] {
+        &self.input_weights[feature * self.hidden..(feature + 1) * self.hidden]
+    }
+
+    /// The score in centipawns for the side to move.
+    fn output(&self, accumulator: &Accumulator, side_to_move: Color) -> i32 {
+        let (own, other) = self.output_weights.split_at(self.hidden);
+        let sum = simd::clipped_dot(&accumulator[side_to_move], own, QA)
+            + simd::clipped_dot(&accumulator[side_to_move.opposite()], other, QA)
+            + i64::from(self.output_bias);
+        let score = sum * SCALE / (i64::from(QA) * i64::from(QB));
+        let bound = i64::from(MAX_SCORE);
+        i32::try_from(score.clamp(-bound, bound)).expect("the bound fits an i32")
+    }
+}
+
+type Accumulator = EnumMap<Color, Box<[i16]>>;
+
+/// Evaluates positions with a [`Network`], keeping one accumulator per ply so that making a
+/// move only adds and subtracts the weights of the pieces it moved.
+pub struct Evaluator<'a> {
+    network: &'a Network,
+    stack: Vec<Accumulator>,
+    top: usize,
+}
+
+impl<'a> Evaluator<'a> {
+    pub fn new(network: &'a Network, board: &Board) -> Self {
+        let accumulator = EnumMap::from_fn(|perspective| network.refresh(board, perspective));
+        Self {
+            network,
+            stack: vec![accumulator],
+            top: 0,
+        }
+    }
+
+    /// Scores `board`, which must be the position the accumulators were last brought up to
+    /// date with, in centipawns for the side to move.
+    pub fn evaluate(&self, board: &Board) -> i32 {
+        self.network
+            .output(&self.stack[self.top], board.current_turn())
+    }
+
+    /// Plays `half_move` on `board` and updates the accumulators to match.
+    pub fn make_move(&mut self, board: &mut Board, half_move: &HalfMove) -> UndoInfo {
+        let changes = changes(board, half_move);
+        let king_buckets = EnumMap::from_fn(|color| king_bucket(board, color));
+        let undo = board.make_move(half_move);
+
+        self.top += 1;
+        if self.top == self.stack.len() {
+            self.stack.push(self.stack[self.top - 1].clone());
+        }
+        let (previous, current) = self.stack.split_at_mut(self.top);
+        let (previous, current) = (&previous[self.top - 1], &mut current[0]);
+        for perspective in Color::iter() {
+            if king_bucket(board, perspective) != king_buckets[perspective] {
+                current[perspective] = self.network.refresh(board, perspective);
+                continue;
+            }
+            let king = board
+                .king_position(perspective)
+                .expect("both kings are on the board");
+            current[perspective].copy_from_slice(&previous[perspective]);
+            for &(piece, position, added) in changes.iter().flatten() {
+                let weights = self
+                    .network
+                    .weights(feature(perspective, king, &piece, position));
+                if added {
+                    simd::add(&mut current[perspective], weights);
+                } else {
+                    simd::subtract(&mut current[perspective], weights);
+                }
+            }
+        }
+        debug_assert!(
+            Color::iter().all(|perspective| {
+                self.stack[self.top][perspective] == self.network.refresh(board, perspective)
+            }),
+            "incremental accumulator diverged from recomputation"
+        );
+        undo
+    }
+
+    /// Takes back `half_move`, which must be the last move made with [`Evaluator::make_move`].
+    pub fn unmake_move(&mut self, board: &mut Board, half_move: &HalfMove, undo: &UndoInfo) {
+        board.unmake_move(half_move, undo);
+        self.top -= 1;
+    }
+}
+
+/// The pieces a move takes off and puts on the board, as `(piece, square, added)`.
+fn changes(board: &Board, half_move: &HalfMove) -> [Option<(Piece, Position, bool)>; 4] {
+    let (from, to) = (*half_move.from(), *half_move.to());
+    let color = board.current_turn();
+    let moved = board
+        .get(from)
+        .expect("a legal move starts on an occupied square");
+    let placed = half_move
+        .promotion()
+        .map_or(moved, |ptype| Piece::new(ptype, color));
+
+    let mut changes = [
+        Some((moved, from, false)),
+        Some((placed, to, true)),
+        None,
+        None,
+    ];
+    match half_move.kind() {
+        MoveKind::EnPassant => {
+            let captured = Position::new(*from.rank(), *to.file());
+            changes[2] = Some((Piece::new(&PAWN, color.opposite()), captured, false));
+        }
+        MoveKind::Castle(side) => {
+            let rook = Piece::new(&ROOK, color);
+            let rank = *from.rank();
+            changes[2] = Some((rook, Position::new(rank, side.rook_file()), false));
+            changes[3] = Some((
+                rook,
+                Position::new(rank, side.rook_destination_file()),
+                true,
+            ));
+        }
+        MoveKind::Normal | MoveKind::DoublePawnPush => {
+            changes[2] = board.get(to).map(|captured| (captured, to, false));
+        }
+    }
+    changes
+}
+
+/// Which set of inputs `perspective` uses, from where its king stands.
+fn king_bucket(board: &Board, perspective: Color) -> usize {
+    board
+        .king_position(perspective)
+        .map_or(0, |king| bucket(relative(perspective, king)))
+}
+
+const fn bucket(relative_king: usize) -> usize {
+    let advanced = relative_king >= 8;
+    let king_side = relative_king % 8 >= 4;
+    advanced as usize * 2 + king_side as usize
+}
+
+/// The square's index with the board flipped for Black, so both perspectives share weights.
+const fn relative(perspective: Color, position: Position) -> usize {
+    match perspective {
+        Color::White => position.index(),
+        Color::Black => position.index() ^ 0b11_1000,
+    }
+}
+
+fn feature(perspective: Color, king: Position, piece: &Piece, position: Position) -> usize {
+    let side = if piece.color() == perspective { 0 } else { 6 };
+    let piece_index = side + piece.ptype().index();
+    bucket(relative(perspective, king)) * BUCKET_INPUTS
+        + piece_index * 64
+        + relative(perspective, position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{board::perft, magic::Xorshift};
+
+    /// A network with `hidden` neurons and small pseudo-random weights drawn from `seed`.
+    fn test_network(hidden: usize, seed: u64) -> Network {
+        let mut rng = Xorshift::new(seed);
+        let mut small = |range: u64| {
+            let value = i64::try_from(rng.next() % (2 * range + 1)).unwrap();
+            i16::try_from(value - i64::try_from(range).unwrap()).unwrap()
+        };
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend(VERSION.to_le_bytes());
+        bytes.extend(u32::try_from(hidden).unwrap().to_le_bytes());
+        for _ in 0..(INPUTS + 3) * hidden {
+            bytes.extend(small(64).to_le_bytes());
+        }
+        bytes.extend(1000i32.to_le_bytes());
+        Network::from_bytes(&bytes).expect("the test network is well formed")
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(Network::from_bytes(b"CHNN").is_err());
+        assert!(Network::from_bytes(b"NOPE\x01\0\0\0\x01\0\0\0").is_err());
+        assert!(Network::from_bytes(b"CHNN\x02\0\0\0\x01\0\0\0").is_err());
+        assert!(Network::from_bytes(b"CHNN\x01\0\0\0\x01\0\0\0\0\0").is_err());
+    }
+
+    /// A network checked in as a file: two hidden neurons, input weights following
+    /// `(37 * index) % 201 - 100` except for the first two, and hand-picked values elsewhere, so
+    /// that byte order and section sizes are visible in the loaded values.
+    const TINY_NETWORK: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/nnue/tiny.nnue");
+
+    #[test]
+    fn tiny_network_file_has_the_documented_layout() {
+        let bytes = fs::read(TINY_NETWORK).unwrap();
+        assert_eq!(&bytes[..4], MAGIC);
+        assert_eq!(bytes[4..8], [1, 0, 0, 0]);
+        assert_eq!(bytes[8..12], [2, 0, 0, 0]);
+        // The first input weight is 0x1234 and the second is -2, both little-endian.
+        assert_eq!(bytes[12..16], [0x34, 0x12, 0xFE, 0xFF]);
+        assert_eq!(bytes.len(), 12 + (INPUTS + 3) * 2 * 2 + 4);
+        assert_eq!(bytes[bytes.len() - 4..], [0x0D, 0x0C, 0x0B, 0x0A]);
+    }
+
+    #[test]
+    fn loads_a_network_file() {
+        let network = Network::load(TINY_NETWORK).expect("the checked-in network loads");
+        assert_eq!(network.hidden, 2);
+        assert_eq!(network.input_weights.len(), INPUTS * 2);
+        assert_eq!(network.input_weights[..4], [0x1234, -2, -26, 11]);
+        let last = INPUTS * 2 - 1;
+        let expected = i16::try_from(last * 37 % 201).unwrap() - 100;
+        assert_eq!(network.input_weights[last], expected);
+        assert_eq!(*network.hidden_biases, [0x0102, -300]);
+        assert_eq!(*network.output_weights, [7, -8, 9, -10]);
+        assert_eq!(network.output_bias, 0x0A0B_0C0D);
+        assert_eq!(network.weights(1), [-26, 11]);
+
+        let board = Board::new();
+        let evaluation = Evaluator::new(&network, &board).evaluate(&board);
+        let scalar = simd::with_scalar(|| Evaluator::new(&network, &board).evaluate(&board));
+        assert_eq!(evaluation, scalar);
+    }
+
+    #[test]
+    fn rejects_network_files_of_the_wrong_size() {
+        let bytes = fs::read(TINY_NETWORK).unwrap();
+        assert!(Network::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let mut longer = bytes.clone();
+        longer.extend([0, 0]);
+        assert!(Network::from_bytes(&longer).is_err());
+        // Claiming three hidden neurons makes the weights too short.
+        let mut wider = bytes;
+        wider[8] = 3;
+        assert!(Network::from_bytes(&wider).is_err());
+
+        let missing = Network::load("/nonexistent/network.nnue");
+        assert!(missing.is_err_and(|error| error.reason.starts_with("cannot read")));
+    }
+
+    /// Plays random games through the evaluator and checks after every move, and every take-back,
+    /// that the incremental accumulators match a full recomputation.
+    fn check_incremental_updates() {
+        // Not a multiple of the vector width, so the scalar tail is exercised too.
+        let network = test_network(40, 0x2545_F491_4F6C_DD1D);
+        let mut rng = Xorshift::new(0xD1B5_4A32_D192_ED03);
+        let (mut castles, mut en_passants, mut promotions, mut bucket_changes) = (0, 0, 0, 0);
+        let assert_matches = |evaluator: &Evaluator, board: &Board| {
+            for perspective in Color::iter() {
+                assert!(
+                    evaluator.stack[evaluator.top][perspective]
+                        == network.refresh(board, perspective),
+                    "{perspective:?} accumulator diverged at {}",
+                    board.to_fen()
+                );
+            }
+            assert_eq!(
+                evaluator.evaluate(board),
+                Evaluator::new(&network, board).evaluate(board)
+            );
+        };
+
+        for (_, fen, _) in perft::SUITE {
+            for _ in 0..8 {
+                let mut board = Board::from_fen(fen).expect("suite FEN strings are valid");
+                let mut evaluator = Evaluator::new(&network, &board);
+                let mut played = Vec::new();
+                for _ in 0..80 {
+                    let moves = board.legal_moves();
+                    let Some(&half_move) = rng.choose(&moves) else {
+                        break;
+                    };
+                    let buckets = EnumMap::from_fn(|color| king_bucket(&board, color));
+                    match half_move.kind() {
+                        MoveKind::Castle(_) => castles += 1,
+                        MoveKind::EnPassant => en_passants += 1,
+                        MoveKind::Normal | MoveKind::DoublePawnPush => {}
+                    }
+                    promotions += usize::from(half_move.promotion().is_some());
+
+                    let undo = evaluator.make_move(&mut board, &half_move);
+                    bucket_changes += Color::iter()
+                        .filter(|&color| king_bucket(&board, color) != buckets[color])
+                        .count();
+                    assert_matches(&evaluator, &board);
+                    played.push((half_move, undo));
+                }
+                while let Some((half_move, undo)) = played.pop() {
+                    evaluator.unmake_move(&mut board, &half_move, &undo);
+                    assert_matches(&evaluator, &board);
+                }
+            }
+        }
+        assert!(castles > 0, "no castling moves were played");
+        assert!(en_passants > 0, "no en passant captures were played");
+        assert!(promotions > 0, "no promotions were played");
+        assert!(bucket_changes > 0, "no king changed bucket");
+    }
+
+    /// Uses AVX2 when the CPU has it; see the scalar twin below.
+    #[test]
+    fn incremental_updates_match_refresh() {
+        check_incremental_updates();
+    }
+
+    #[test]
+    fn scalar_incremental_updates_match_refresh() {
+        simd::with_scalar(check_incremental_updates);
+    }
+
+    /// Plays the same random games with a scalar and a vectorized evaluator side by side.
+    #[test]
+    fn scalar_and_vector_evaluators_agree() {
+        let network = test_network(40, 0x9E37_79B9_7F4A_7C15);
+        let mut rng = Xorshift::new(0xA076_1D64_78BD_642F);
+        for (_, fen, _) in perft::SUITE {
+            let mut board = Board::from_fen(fen).expect("suite FEN strings are valid");
+            let mut vector = Evaluator::new(&network, &board);
+            let mut scalar = simd::with_scalar(|| Evaluator::new(&network, &board));
+            for _ in 0..60 {
+                assert_eq!(vector.stack[vector.top], scalar.stack[scalar.top]);
+                assert_eq!(
+                    vector.evaluate(&board),
+                    simd::with_scalar(|| scalar.evaluate(&board))
+                );
+                let Some(&half_move) = rng.choose(&board.legal_moves()) else {
+                    break;
+                };
+                let mut scalar_board = board.clone();
+                simd::with_scalar(|| scalar.make_move(&mut scalar_board, &half_move));
+                vector.make_move(&mut board, &half_move);
+            }
+        }
+    }
+
+    #[test]
+    fn kernels_agree_on_every_length() {
+        if !simd::has_avx2() {
+            eprintln!("AVX2 is not available, so only the scalar kernels ran");
+        }
+        let mut rng = Xorshift::new(0x5851_F42D_4C95_7F2D);
+        #[allow(clippy::cast_possible_truncation)]
+        let mut random =
+            |length: usize| -> Vec<i16> { (0..length).map(|_| rng.next() as i16).collect() };
+        for length in (0..70).chain([1024, 1025, 3100]) {
+            let (accumulator, weights) = (random(length), random(length));
+            for operation in [simd::add, simd::subtract] {
+                let mut vector = accumulator.clone();
+                operation(&mut vector, &weights);
+                let mut scalar = accumulator.clone();
+                simd::with_scalar(|| operation(&mut scalar, &weights));
+                assert_eq!(vector, scalar, "length {length}");
+            }
+            for max in [0, 1, QA] {
+                assert_eq!(
+                    simd::clipped_dot(&accumulator, &weights, max),
+                    simd::with_scalar(|| simd::clipped_dot(&accumulator, &weights, max)),
+                    "length {length}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn dot_product_does_not_overflow() {
+        // Long enough to need several AVX2 blocks, plus a scalar tail.
+        let length = 3100;
+        let activations = vec![QA; length];
+        for weight in [i16::MIN, i16::MAX] {
+            let weights = vec![weight; length];
+            let expected = i64::from(QA) * i64::from(weight) * i64::try_from(length).unwrap();
+            assert_eq!(simd::clipped_dot(&activations, &weights, QA), expected);
+        }
+    }
+
+    #[test]
+    fn output_is_clamped_below_mate_scores() {
+        let hidden = 2048;
+        let network = Network {
+            hidden,
+            input_weights: vec![0; INPUTS * hidden].into(),
+            hidden_biases: vec![QA; hidden].into(),
+            output_weights: vec![i16::MAX; 2 * hidden].into(),
+            output_bias: i32::MAX,
+        };
+        let board = Board::new();
+        let evaluator = Evaluator::new(&network, &board);
+        assert_eq!(evaluator.evaluate(&board), MAX_SCORE);
+    }
+}
@@ -0,0 +1,170 @@
+//! Vector kernels for the accumulator, using AVX2 when the CPU has it and plain loops
+//! otherwise. Both paths wrap the accumulator on overflow and compute the dot product exactly,
+//! so they always agree.
+
+#[cfg(test)]
+use std::cell::Cell;
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::{
+    __m256i, _mm256_add_epi16, _mm256_add_epi32, _mm256_loadu_si256, _mm256_madd_epi16,
+    _mm256_max_epi16, _mm256_min_epi16, _mm256_set1_epi16, _mm256_setzero_si256,
+    _mm256_storeu_si256, _mm256_sub_epi16,
+};
+
+/// Sixteen `i16` lanes fit in one AVX2 register.
+#[cfg(target_arch = "x86_64")]
+const LANES: usize = 16;
+/// How many activations the AVX2 dot product sums in `i32` lanes before widening to `i64`.
+/// Each pair of products is at most 2 · 255 · 32768 in magnitude, so 64 of them cannot overflow.
+#[cfg(target_arch = "x86_64")]
+const BLOCK: usize = 64 * LANES;
+
+#[cfg(test)]
+thread_local! {
+    /// Lets tests run the scalar kernels on a CPU that has AVX2.
+    static FORCE_SCALAR: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` with the scalar kernels on the current thread, whatever the CPU supports.
+#[cfg(test)]
+pub fn with_scalar<T>(f: impl FnOnce() -> T) -> T {
+    FORCE_SCALAR.set(true);
+    let result = f();
+    FORCE_SCALAR.set(false);
+    result
+}
+
+#[cfg(target_arch = "x86_64")]
+pub fn has_avx2() -> bool {
+    #[cfg(test)]
+    if FORCE_SCALAR.get() {
+        return false;
+    }
+    is_x86_feature_detected!("avx2")
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub const fn has_avx2() -> bool {
+    false
+}
+
+pub fn add(accumulator: &mut [i16], weights: &[i16]) {
+    #[cfg(target_arch = "x86_64")]
+    if has_avx2() {
+        // SAFETY: the CPU supports AVX2, checked just above.
+        unsafe { avx2::add(accumulator, weights) };
+        return;
+    }
+    scalar::add(accumulator, weights);
+}
+
+pub fn subtract(accumulator: &mut [i16], weights: &[i16]) {
+    #[cfg(target_arch = "x86_64")]
+    if has_avx2() {
+        // SAFETY: the CPU supports AVX2, checked just above.
+        unsafe { avx2::subtract(accumulator, weights) };
+        return;
+    }
+    scalar::subtract(accumulator, weights);
+}
+
+/// The dot product of `weights` with the activations clipped to `0..=max`, where `max` is at
+/// most 255.
+pub fn clipped_dot(activations: &[i16], weights: &[i16], max: i16) -> i64 {
+    debug_assert!(
+        (0..=255).contains(&max),
+        "the kernels are sized for 8-bit activations"
+    );
+    #[cfg(target_arch = "x86_64")]
+    if has_avx2() {
+        // SAFETY: the CPU supports AVX2, checked just above.
+        return unsafe { avx2::clipped_dot(activations, weights, max) };
+    }
+    scalar::clipped_dot(activations, weights, max)
+}
+
+mod scalar {
+    pub fn add(accumulator: &mut [i16], weights: &[i16]) {
+        for (value, weight) in accumulator.iter_mut().zip(weights) {
+            *value = value.wrapping_add(*weight);
+        }
+    }
+
+    pub fn subtract(accumulator: &mut [i16], weights: &[i16]) {
+        for (value, weight) in accumulator.iter_mut().zip(weights) {
+            *value = value.wrapping_sub(*weight);
+        }
+    }
+
+    pub fn clipped_dot(activations: &[i16], weights: &[i16], max: i16) -> i64 {
+        activations
+            .iter()
+            .zip(weights)
+            .map(|(activation, weight)| i64::from((*activation).clamp(0, max)) * i64::from(*weight))
+            .sum()
+    }
+}
+
+// Unaligned loads and stores are used throughout, so the pointer casts need no alignment.
+#[cfg(target_arch = "x86_64")]
+#[allow(clippy::cast_ptr_alignment)]
+mod avx2 {
+    use super::{
+        __m256i, _mm256_add_epi16, _mm256_add_epi32, _mm256_loadu_si256, _mm256_madd_epi16,
+        _mm256_max_epi16, _mm256_min_epi16, _mm256_set1_epi16, _mm256_setzero_si256,
+        _mm256_storeu_si256, _mm256_sub_epi16, scalar, BLOCK, LANES,
+    };
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn add(accumulator: &mut [i16], weights: &[i16]) {
+        let length = accumulator.len().min(weights.len());
+        let vectorized = length - length % LANES;
+        for start in (0..vectorized).step_by(LANES) {
+            let target = accumulator[start..].as_mut_ptr().cast::<__m256i>();
+            let weights = weights[start..].as_ptr().cast::<__m256i>();
+            _mm256_storeu_si256(
+                target,
+                _mm256_add_epi16(_mm256_loadu_si256(target), _mm256_loadu_si256(weights)),
+            );
+        }
+        scalar::add(&mut accumulator[vectorized..], &weights[vectorized..]);
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn subtract(accumulator: &mut [i16], weights: &[i16]) {
+        let length = accumulator.len().min(weights.len());
+        let vectorized = length - length % LANES;
+        for start in (0..vectorized).step_by(LANES) {
+            let target = accumulator[start..].as_mut_ptr().cast::<__m256i>();
+            let weights = weights[start..].as_ptr().cast::<__m256i>();
+            _mm256_storeu_si256(
+                target,
+                _mm256_sub_epi16(_mm256_loadu_si256(target), _mm256_loadu_si256(weights)),
+            );
+        }
+        scalar::subtract(&mut accumulator[vectorized..], &weights[vectorized..]);
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn clipped_dot(activations: &[i16], weights: &[i16], max: i16) -> i64 {
+        let length = activations.len().min(weights.len());
+        let vectorized = length - length % LANES;
+        let (zero, max_vector) = (_mm256_setzero_si256(), _mm256_set1_epi16(max));
+        let mut total =
+            scalar::clipped_dot(&activations[vectorized..], &weights[vectorized..], max);
+        for block in (0..vectorized).step_by(BLOCK) {
+            let mut sums = _mm256_setzero_si256();
+            for start in (block..vectorized.min(block + BLOCK)).step_by(LANES) {
+                let activation = _mm256_loadu_si256(activations[start..].as_ptr().cast());
+                let clipped = _mm256_min_epi16(_mm256_max_epi16(activation, zero), max_vector);
+                let weight = _mm256_loadu_si256(weights[start..].as_ptr().cast());
+                sums = _mm256_add_epi32(sums, _mm256_madd_epi16(clipped, weight));
+            }
+            let mut lanes = [0i32; 8];
+            _mm256_storeu_si256(lanes.as_mut_ptr().cast(), sums);
+            total += lanes.into_iter().map(i64::from).sum::<i64>();
+        }
+        total
+    }
+}
@@ -0,0 +1,92 @@
+#![warn(clippy::pedantic, clippy::nursery)]
+#![feature(iter_intersperse)]
+#![allow(dead_code)]
+
+use std::{env, process::ExitCode, time::Instant};
+
+use board::{perft::SUITE, Board, START_FEN};
+
+mod bitboard;
+mod board;
+mod clock;
+mod errors;
+mod eval;
+mod game;
+mod half_move;
+mod magic;
+mod nnue;
+mod piece;
+mod piece_map;
+mod pieces;
+mod player;
+mod position;
+mod search;
+#[rustfmt::skip]
+mod tables;
+mod zobrist;
+
+const USAGE: &str = "usage: perft <depth> [fen]\n       perft suite [max depth]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("suite") => {
+            let max_depth = args.get(1).map_or(Some(4), |depth| depth.parse().ok());
+            max_depth.map_or_else(usage, suite)
+        }
+        Some(depth) => depth
+            .parse()
+            .map_or_else(|_| usage(), |depth| divide(depth, &args[1..].join(" "))),
+        None => usage(),
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{USAGE}");
+    ExitCode::FAILURE
+}
+
+fn divide(depth: u32, fen: &str) -> ExitCode {
+    let fen = if fen.is_empty() { START_FEN } else { fen };
+    let Ok(board) = Board::from_fen(fen) else {
+        eprintln!("Invalid FEN string: {fen}");
+        return ExitCode::FAILURE;
+    };
+
+    let start = Instant::now();
+    let divided = board.perft_divide(depth);
+    for (half_move, nodes) in &divided {
+        println!("{half_move}: {nodes}");
+    }
+    let total: u64 = divided.iter().map(|(_, nodes)| nodes).sum();
+    println!("\nNodes searched: {total} in {:.2?}", start.elapsed());
+    ExitCode::SUCCESS
+}
+
+fn suite(max_depth: usize) -> ExitCode {
+    let mut failures = 0;
+    for (name, fen, expected_counts) in SUITE {
+        let board = Board::from_fen(fen).expect("suite FEN strings are valid");
+        for (depth, &expected) in (1..).zip(expected_counts.iter().take(max_depth)) {
+            let start = Instant::now();
+            let nodes = board.perft(depth);
+            let status = if nodes == expected {
+                "ok"
+            } else {
+                failures += 1;
+                "FAILED"
+            };
+            println!(
+                "{name} depth {depth}: {nodes} (expected {expected}) {status} in {:.2?}",
+                start.elapsed()
+            );
+        }
+    }
+
+    if failures == 0 {
+        ExitCode::SUCCESS
+    } else {
+        println!("{failures} perft counts did not match");
+        ExitCode::FAILURE
+    }
+}
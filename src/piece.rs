@@ -2,10 +2,15 @@ use enum_map::Enum;
 use strum_macros::EnumIter;
 
 use crate::{
-    board::Board, errors::InvalidChar, half_move::HalfMove, pieces, pieces::PieceType,
-    position::Position,
+    board::Board,
+    errors::InvalidChar,
+    half_move::HalfMove,
+    pieces,
+    pieces::PieceType,
+    position::{Axis, Position},
 };
 
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Piece {
     ptype: &'static PieceType,
     color: Color,
@@ -66,12 +71,28 @@ impl From<&Piece> for char {
     }
 }
 
-#[derive(Enum, Clone, Copy, EnumIter, PartialEq, Eq)]
+#[derive(Enum, Clone, Copy, EnumIter, PartialEq, Eq, Debug)]
 pub enum Color {
     White,
     Black,
 }
 
+impl Color {
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::White => Self::Black,
+            Self::Black => Self::White,
+        }
+    }
+
+    pub const fn back_rank(self) -> Axis {
+        match self {
+            Self::White => Axis::A,
+            Self::Black => Axis::H,
+        }
+    }
+}
+
 impl From<Color> for char {
     fn from(val: Color) -> Self {
         match val {
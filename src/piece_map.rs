@@ -5,7 +5,7 @@ use crate::{
     pieces::PIECE_TYPES,
 };
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct PieceMap<T: Clone + Copy> {
     map: EnumMap<Color, [T; 6]>,
 }
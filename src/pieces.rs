@@ -1,8 +1,10 @@
-use crate::{board::Board, half_move::HalfMove, piece::Color, position::Position};
+use crate::{
+    bitboard::Bitboard, board::Board, half_move::HalfMove, piece::Color, position::Position,
+};
 mod bishop;
-mod king;
-mod knight;
-mod pawn;
+pub mod king;
+pub mod knight;
+pub mod pawn;
 mod queen;
 mod rook;
 pub use bishop::BISHOP;
@@ -33,6 +35,13 @@ pub fn get_piece_type_from_char(character: char) -> Option<&'static PieceType> {
     }
 }
 
+fn moves_to_targets(position: Position, targets: Bitboard) -> Box<[HalfMove]> {
+    targets
+        .into_iter()
+        .map(|target| HalfMove::new(position, target))
+        .collect()
+}
+
 impl PieceType {
     pub const fn piece_char(&self) -> char {
         self.piece_char
@@ -51,3 +60,11 @@ impl PieceType {
         self.piece_index
     }
 }
+
+impl PartialEq for PieceType {
+    fn eq(&self, other: &Self) -> bool {
+        self.piece_index == other.piece_index
+    }
+}
+
+impl Eq for PieceType {}
@@ -1,5 +1,13 @@
+use strum::IntoEnumIterator;
+
 use crate::{
-    board::Board, half_move::HalfMove, piece::Color, pieces::PieceType, position::Position,
+    bitboard::Bitboard,
+    board::{Board, BoardSide},
+    half_move::{HalfMove, MoveKind},
+    piece::{Color, Piece},
+    pieces::{PieceType, ROOK},
+    position::{Axis, Position},
+    tables,
 };
 
 pub static KING: PieceType = PieceType {
@@ -9,8 +17,34 @@ pub static KING: PieceType = PieceType {
     piece_char: 'k',
 };
 
+pub const fn attacks(position: Position) -> Bitboard {
+    Bitboard::from_data(tables::KING_ATTACKS[position.index()])
+}
+
 fn generate_moves(board: &Board, color: Color, position: Position) -> Box<[HalfMove]> {
-    _ = (board, color, position);
-    println!("King moves");
-    todo!();
+    (attacks(position) & !board.occupied_by(color))
+        .into_iter()
+        .map(|target| HalfMove::new(position, target))
+        .chain(castling_moves(board, color, position))
+        .collect()
+}
+
+/// Castling moves whose path is clear. Whether the king passes through check is left to legal
+/// move generation.
+fn castling_moves(
+    board: &Board,
+    color: Color,
+    position: Position,
+) -> impl Iterator<Item = HalfMove> + '_ {
+    let home = Position::new(color.back_rank(), Axis::E);
+    let rooks = board.pieces(&Piece::new(&ROOK, color));
+    BoardSide::iter().filter_map(move |side| {
+        let rook = Position::new(color.back_rank(), side.rook_file());
+        let path_clear = (Bitboard::between(position, rook) & board.occupied()).is_empty();
+        (position == home && board.castling_right(color, side) && rooks.get(rook) && path_clear)
+            .then(|| {
+                let destination = Position::new(color.back_rank(), side.king_destination_file());
+                HalfMove::with_kind(position, destination, MoveKind::Castle(side))
+            })
+    })
 }
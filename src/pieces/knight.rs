@@ -1,5 +1,11 @@
 use crate::{
-    board::Board, half_move::HalfMove, piece::Color, pieces::PieceType, position::Position,
+    bitboard::Bitboard,
+    board::Board,
+    half_move::HalfMove,
+    piece::Color,
+    pieces::{moves_to_targets, PieceType},
+    position::Position,
+    tables,
 };
 
 pub static KNIGHT: PieceType = PieceType {
@@ -9,7 +15,10 @@ pub static KNIGHT: PieceType = PieceType {
     piece_char: 'n',
 };
 
+pub const fn attacks(position: Position) -> Bitboard {
+    Bitboard::from_data(tables::KNIGHT_ATTACKS[position.index()])
+}
+
 fn generate_moves(board: &Board, color: Color, position: Position) -> Box<[HalfMove]> {
-    _ = (board, color, position);
-    todo!("Knight moves not yet implemented");
+    moves_to_targets(position, attacks(position) & !board.occupied_by(color))
 }
@@ -1,5 +1,11 @@
 use crate::{
-    board::Board, half_move::HalfMove, piece::Color, pieces::PieceType, position::Position,
+    bitboard::Bitboard,
+    board::Board,
+    half_move::{HalfMove, MoveKind},
+    piece::Color,
+    pieces::{PieceType, BISHOP, KNIGHT, QUEEN, ROOK},
+    position::{Axis, Position},
+    tables,
 };
 
 pub static PAWN: PieceType = PieceType {
@@ -9,8 +15,79 @@ pub static PAWN: PieceType = PieceType {
     piece_char: 'p',
 };
 
+pub static PROMOTION_TYPES: [&PieceType; 4] = [&QUEEN, &ROOK, &BISHOP, &KNIGHT];
+
+const fn forward(color: Color) -> i32 {
+    match color {
+        Color::White => 1,
+        Color::Black => -1,
+    }
+}
+
+const fn start_rank(color: Color) -> Axis {
+    match color {
+        Color::White => Axis::B,
+        Color::Black => Axis::G,
+    }
+}
+
+const fn promotion_rank(color: Color) -> Axis {
+    match color {
+        Color::White => Axis::H,
+        Color::Black => Axis::A,
+    }
+}
+
+pub const fn attacks(color: Color, position: Position) -> Bitboard {
+    Bitboard::from_data(tables::PAWN_ATTACKS[color as usize][position.index()])
+}
+
+fn push_move(moves: &mut Vec<HalfMove>, color: Color, from: Position, to: Position) {
+    if *to.rank() == promotion_rank(color) {
+        moves.extend(
+            PROMOTION_TYPES
+                .iter()
+                .map(|ptype| HalfMove::with_promotion(from, to, ptype)),
+        );
+    } else {
+        moves.push(HalfMove::new(from, to));
+    }
+}
+
 fn generate_moves(board: &Board, color: Color, position: Position) -> Box<[HalfMove]> {
-    _ = (board, color, position);
-    println!("Pawn moves");
-    todo!();
+    let mut moves = Vec::new();
+    let occupied = board.occupied();
+
+    if let Some(single) = position.offset(forward(color), 0) {
+        if !occupied.get(single) {
+            push_move(&mut moves, color, position, single);
+            if *position.rank() == start_rank(color) {
+                if let Some(double) = single.offset(forward(color), 0) {
+                    if !occupied.get(double) {
+                        moves.push(HalfMove::with_kind(
+                            position,
+                            double,
+                            MoveKind::DoublePawnPush,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    let attacks = attacks(color, position);
+    for target in attacks & board.occupied_by(color.opposite()) {
+        push_move(&mut moves, color, position, target);
+    }
+    if let Some(en_passant_target) = board.en_passant_target() {
+        if attacks.get(en_passant_target) {
+            moves.push(HalfMove::with_kind(
+                position,
+                en_passant_target,
+                MoveKind::EnPassant,
+            ));
+        }
+    }
+
+    moves.into_boxed_slice()
 }
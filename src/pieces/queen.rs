@@ -1,5 +1,10 @@
 use crate::{
-    board::Board, half_move::HalfMove, piece::Color, pieces::PieceType, position::Position,
+    board::Board,
+    half_move::HalfMove,
+    magic,
+    piece::Color,
+    pieces::{moves_to_targets, PieceType},
+    position::Position,
 };
 
 pub static QUEEN: PieceType = PieceType {
@@ -10,7 +15,6 @@ pub static QUEEN: PieceType = PieceType {
 };
 
 fn generate_moves(board: &Board, color: Color, position: Position) -> Box<[HalfMove]> {
-    _ = (board, color, position);
-    println!("Queen moves");
-    todo!();
+    let targets = magic::queen_attacks(position, board.occupied()) & !board.occupied_by(color);
+    moves_to_targets(position, targets)
 }
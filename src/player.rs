@@ -1,5 +1,129 @@
-use crate::{board::Board, half_move::HalfMove};
+use std::{
+    future::Future,
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use tokio::task;
+
+use crate::{board::Board, clock::Clock, errors::InvalidMove, half_move::HalfMove};
+
+/// What a player can do on its turn.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Move(HalfMove),
+    Resign,
+    /// Offers the opponent a draw, which stands until its next move. The turn goes on, so the
+    /// player is asked for another action.
+    OfferDraw,
+    /// Accepts the opponent's standing draw offer.
+    AcceptDraw,
+    /// Turns down the opponent's draw offer and goes on with the turn. Moving turns it down
+    /// too.
+    DeclineDraw,
+    /// Claims a draw by threefold repetition or the fifty-move rule in the current position.
+    ClaimDraw,
+}
+
+/// What a player is told when it is asked to act.
+pub struct Turn<'a> {
+    pub board: &'a Board,
+    /// Both sides' time as it stood when the turn began, if the game is timed.
+    pub clock: Option<&'a Clock>,
+    /// Whether the opponent has offered a draw that is still standing.
+    pub draw_offered: bool,
+    /// Raised by [`Blocking`] once nobody waits for this turn's action any more, because the move
+    /// timed out or the game was abandoned. A player that thinks for long should watch it and
+    /// return soon after, with any action, as it will be ignored.
+    pub stop: &'a AtomicBool,
+}
 
 pub trait Player: Send + Sync {
-    fn suggest_move(&self, board: &Board) -> HalfMove;
+    /// Chooses what to do for the side to move in `turn.board`.
+    fn act(&self, turn: &Turn) -> Action;
+
+    /// Called when the game rejected `action` and is about to ask for another, with the
+    /// reason it was not allowed.
+    fn action_rejected(&self, _action: &Action, _reason: &InvalidMove) {}
+
+    /// Called before the first move of every game, so the player can forget the previous one.
+    fn new_game(&self) {}
+}
+
+/// A player that can wait for its move without holding a thread, such as a remote opponent, so
+/// that one runtime can host many games at once. [`Blocking`] turns a [`Player`] into one.
+pub trait AsyncPlayer: Send + Sync {
+    /// Chooses what to do for the side to move in `turn.board`.
+    fn act(&self, turn: &Turn) -> impl Future<Output = Action> + Send;
+
+    /// Called when the game rejected `action` and is about to ask for another, with the
+    /// reason it was not allowed.
+    fn action_rejected(&self, _action: &Action, _reason: &InvalidMove) {}
+
+    /// Called before the first move of every game, so the player can forget the previous one.
+    fn new_game(&self) {}
+}
+
+/// Runs a [`Player`] on the runtime's blocking thread pool, so its thinking does not stall the
+/// other games. Dropping the action's future, as a move timeout or an abandoned game does,
+/// raises the player's [`Turn::stop`], and its thread is free again once the player notices.
+pub struct Blocking<P> {
+    player: Arc<P>,
+}
+
+impl<P: Player + 'static> Blocking<P> {
+    pub fn new(player: P) -> Self {
+        Self {
+            player: Arc::new(player),
+        }
+    }
+
+    pub fn player(&self) -> &P {
+        &self.player
+    }
+}
+
+impl<P: Player + 'static> AsyncPlayer for Blocking<P> {
+    fn act(&self, turn: &Turn) -> impl Future<Output = Action> + Send {
+        let player = Arc::clone(&self.player);
+        let board = turn.board.clone();
+        let clock = turn.clock.copied();
+        let draw_offered = turn.draw_offered;
+        async move {
+            let stop = Arc::new(AtomicBool::new(false));
+            let _stop_on_drop = StopOnDrop(Arc::clone(&stop));
+            let action = task::spawn_blocking(move || {
+                player.act(&Turn {
+                    board: &board,
+                    clock: clock.as_ref(),
+                    draw_offered,
+                    stop: &stop,
+                })
+            });
+            // The task is only cancelled when the runtime shuts down, so this is a panic.
+            action
+                .await
+                .unwrap_or_else(|error| panic::resume_unwind(error.into_panic()))
+        }
+    }
+
+    fn action_rejected(&self, action: &Action, reason: &InvalidMove) {
+        self.player.action_rejected(action, reason);
+    }
+
+    fn new_game(&self) {
+        self.player.new_game();
+    }
+}
+
+/// Raises the flag when dropped, so a blocking player hears that its action is no longer wanted.
+struct StopOnDrop(Arc<AtomicBool>);
+
+impl Drop for StopOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
 }
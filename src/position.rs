@@ -1,9 +1,11 @@
+use std::{fmt::Display, str::FromStr};
+
 use enum_map::Enum;
 use strum_macros::{EnumIter, FromRepr};
 
-use crate::errors::{InvalidChar, PositionOutOfBounds};
+use crate::errors::{InvalidChar, InvalidNotation, PositionOutOfBounds};
 
-#[derive(Enum, Clone, Copy, Debug)]
+#[derive(Enum, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Position {
     rank: Axis,
     file: Axis,
@@ -25,15 +27,59 @@ impl Position {
     pub const fn index(self) -> usize {
         self.rank as usize * 8 + self.file as usize
     }
+
+    pub fn offset(self, rank_offset: i32, file_offset: i32) -> Option<Self> {
+        let rank = usize::try_from(self.rank as i32 + rank_offset).ok()?;
+        let file = usize::try_from(self.file as i32 + file_offset).ok()?;
+        Some(Self::new(Axis::from_repr(rank)?, Axis::from_repr(file)?))
+    }
+
+    /// The file's letter, from `'a'` to `'h'`.
+    pub const fn file_char(self) -> char {
+        (b'a' + self.file as u8) as char
+    }
+
+    /// The rank's digit, from `'1'` to `'8'`.
+    pub const fn rank_char(self) -> char {
+        (b'1' + self.rank as u8) as char
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.file_char(), self.rank_char())
+    }
 }
 
+/// Parses a square name such as `['e', '4']`.
 impl TryFrom<[char; 2]> for Position {
     type Error = InvalidChar;
     fn try_from(chars: [char; 2]) -> Result<Self, Self::Error> {
-        let rank = Axis::from_repr(chars[0] as usize - 'A' as usize).ok_or(InvalidChar {})?;
-        let file = Axis::from_repr(chars[1].to_digit(10).ok_or(InvalidChar {})? as usize)
+        let file = ('a'..='h')
+            .position(|c| c == chars[0])
             .ok_or(InvalidChar {})?;
-        Ok(Self::new(rank, file))
+        let rank = ('1'..='8')
+            .position(|c| c == chars[1])
+            .ok_or(InvalidChar {})?;
+        Ok(Self::new(
+            Axis::from_repr(rank).ok_or(InvalidChar {})?,
+            Axis::from_repr(file).ok_or(InvalidChar {})?,
+        ))
+    }
+}
+
+impl FromStr for Position {
+    type Err = InvalidNotation;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut chars = string.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(file), Some(rank), None) => Self::try_from([file, rank]).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| InvalidNotation {
+            reason: format!("'{string}' is not a square name"),
+        })
     }
 }
 
@@ -42,13 +88,13 @@ impl TryFrom<usize> for Position {
 
     fn try_from(value: usize) -> Result<Self, Self::Error> {
         Ok(Self::new(
-            Axis::from_repr(value % 8).ok_or(Self::Error {})?,
             Axis::from_repr(value / 8).ok_or(Self::Error {})?,
+            Axis::from_repr(value % 8).ok_or(Self::Error {})?,
         ))
     }
 }
 
-#[derive(Enum, Clone, Copy, EnumIter, FromRepr, Debug)]
+#[derive(Enum, Clone, Copy, EnumIter, FromRepr, Debug, PartialEq, Eq, Hash)]
 pub enum Axis {
     A,
     B,
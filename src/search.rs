@@ -0,0 +1,726 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    board::{Board, UndoInfo},
+    clock::Delay,
+    eval,
+    half_move::{HalfMove, MoveKind},
+    nnue::{Evaluator, Network},
+    pieces::PAWN,
+    player::{Action, Player, Turn},
+};
+
+mod time;
+mod tt;
+
+pub use time::{TimeLimits, TimeManager};
+pub use tt::{Bound, Entry, TranspositionTable};
+
+/// The score for delivering mate right now. Mates further away score one less per ply, so
+/// anything within [`MAX_PLY`] of this is a forced mate.
+pub const MATE: i32 = 30_000;
+pub const MAX_DEPTH: u32 = 64;
+/// How far from the root the search may reach, quiescence included.
+pub const MAX_PLY: u32 = 128;
+const INFINITY: i32 = MATE + 1;
+
+/// Piece values in centipawns, indexed like [`PIECE_TYPES`].
+const PIECE_VALUES: [i32; 6] = [100, 320, 330, 500, 900, 0];
+
+/// When a search should stop. Every limit that is set applies, and the search also stops as
+/// soon as the shared stop flag is raised.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct SearchLimits {
+    pub depth: Option<u32>,
+    /// Counts the nodes of the reporting thread alone.
+    pub nodes: Option<u64>,
+    pub movetime: Option<Duration>,
+    /// Time left on the clock of the side to move.
+    pub time_left: Option<Duration>,
+    pub increment: Duration,
+    pub moves_to_go: Option<u32>,
+    /// Time kept back from every move for communication delays.
+    pub move_overhead: Duration,
+    /// Search until stopped, ignoring every other limit.
+    pub infinite: bool,
+    /// How many threads search in parallel. Zero counts as one.
+    pub threads: usize,
+}
+
+impl SearchLimits {
+    /// The clock settings that bound the time of this search, or `None` when it is infinite.
+    pub const fn time_limits(&self) -> Option<TimeLimits> {
+        if self.infinite {
+            return None;
+        }
+        Some(TimeLimits {
+            movetime: self.movetime,
+            time_left: self.time_left,
+            increment: self.increment,
+            moves_to_go: self.moves_to_go,
+            move_overhead: self.move_overhead,
+        })
+    }
+}
+
+/// Progress reported after each completed iteration.
+#[derive(Clone)]
+pub struct SearchInfo {
+    pub depth: u32,
+    /// Centipawns from the point of view of the side to move, or a [`MATE`] score.
+    pub score: i32,
+    pub nodes: u64,
+    pub elapsed: Duration,
+    pub pv: Vec<HalfMove>,
+    /// How full the transposition table is, in permille.
+    pub hashfull: u32,
+}
+
+impl SearchInfo {
+    /// The number of moves until mate, negative if the side to move is being mated.
+    pub fn mate_in(&self) -> Option<i32> {
+        let plies = MATE - self.score.abs();
+        (plies <= MAX_PLY.cast_signed()).then(|| (plies + 1) / 2 * self.score.signum())
+    }
+}
+
+/// Searches `board` one depth at a time, calling `report` after every completed depth, and
+/// returns the best move found before a limit was hit. Only returns `None` when there are no
+/// legal moves. Results are shared with other searches through `table`. Positions are scored by
+/// `network` if one is given, and by the hand-crafted evaluation otherwise.
+///
+/// With more than one thread in `limits`, helper threads search the same position alongside
+/// this one and share what they find through `table`, while this thread alone decides when to
+/// stop and reports. The helpers make the search nondeterministic, so a single thread with a
+/// depth or node limit is the only setup that reproduces its results.
+pub fn search(
+    board: &Board,
+    limits: &SearchLimits,
+    table: &TranspositionTable,
+    network: Option<&Network>,
+    stop: &AtomicBool,
+    mut report: impl FnMut(&SearchInfo),
+) -> Option<HalfMove> {
+    table.new_search();
+    let node_counts: Vec<AtomicU64> = (0..limits.threads.max(1))
+        .map(|_| AtomicU64::new(0))
+        .collect();
+    let helpers_stop = AtomicBool::new(false);
+    let helper_limits = SearchLimits {
+        infinite: true,
+        ..SearchLimits::default()
+    };
+    let max_depth = if limits.infinite {
+        MAX_DEPTH
+    } else {
+        limits.depth.unwrap_or(MAX_DEPTH).clamp(1, MAX_DEPTH)
+    };
+
+    thread::scope(|scope| {
+        for (helper, node_count) in node_counts.iter().enumerate().skip(1) {
+            let mut searcher = Searcher::new(
+                board,
+                &helper_limits,
+                table,
+                network,
+                &helpers_stop,
+                node_count,
+            );
+            let mut board = board.clone();
+            // Every other helper runs a ply ahead, so the table fills with deeper results
+            // than the main thread's before it gets there.
+            let first_depth = if helper % 2 == 1 { 2 } else { 1 };
+            scope.spawn(move || searcher.deepen(&mut board, first_depth..=MAX_DEPTH, |_, _| {}));
+        }
+
+        let mut searcher = Searcher::new(board, limits, table, network, stop, &node_counts[0]);
+        let mut board = board.clone();
+        let fallback = searcher
+            .ordered_moves(&board, &board.legal_moves(), 0, None)
+            .first()
+            .copied();
+        let best_move = searcher.deepen(&mut board, 1..=max_depth, |searcher, info| {
+            searcher.node_count.store(searcher.nodes, Ordering::Relaxed);
+            info.nodes = node_counts
+                .iter()
+                .map(|count| count.load(Ordering::Relaxed))
+                .sum();
+            report(info);
+        });
+        helpers_stop.store(true, Ordering::Relaxed);
+        best_move.or(fallback)
+    })
+}
+
+/// Runs a quiescence search on `board` with the hand-crafted evaluation, returning the score
+/// for the side to move and the captures leading to the quiet position it was taken from.
+pub fn quiesce(board: &Board) -> (i32, Vec<HalfMove>) {
+    // Quiescence never touches the table, so the smallest one will do.
+    let table = TranspositionTable::new(0);
+    let stop = AtomicBool::new(false);
+    let node_count = AtomicU64::new(0);
+    let limits = SearchLimits::default();
+    let mut searcher = Searcher::new(board, &limits, &table, None, &stop, &node_count);
+    let mut pv = Vec::new();
+    let score = searcher.quiescence(&mut board.clone(), -INFINITY, INFINITY, 0, &mut pv);
+    (score, pv)
+}
+
+struct Searcher<'a> {
+    limits: &'a SearchLimits,
+    table: &'a TranspositionTable,
+    stop: &'a AtomicBool,
+    start: Instant,
+    time: Option<TimeManager>,
+    nodes: u64,
+    /// Where this thread publishes its node count for the reporting thread to add up.
+    node_count: &'a AtomicU64,
+    aborted: bool,
+    /// The principal variation of the last completed iteration, searched first in the next.
+    previous_pv: Vec<HalfMove>,
+    evaluator: Option<Evaluator<'a>>,
+}
+
+impl<'a> Searcher<'a> {
+    fn new(
+        board: &Board,
+        limits: &'a SearchLimits,
+        table: &'a TranspositionTable,
+        network: Option<&'a Network>,
+        stop: &'a AtomicBool,
+        node_count: &'a AtomicU64,
+    ) -> Self {
+        Self {
+            limits,
+            table,
+            stop,
+            start: Instant::now(),
+            time: limits
+                .time_limits()
+                .and_then(|time| TimeManager::new(&time)),
+            nodes: 0,
+            node_count,
+            aborted: false,
+            previous_pv: Vec::new(),
+            evaluator: network.map(|network| Evaluator::new(network, board)),
+        }
+    }
+
+    /// Iterative deepening over `depths`, calling `report` after every completed depth, until
+    /// the depths run out, a limit is hit or a mate is found. Returns the best move of the
+    /// deepest completed depth.
+    fn deepen(
+        &mut self,
+        board: &mut Board,
+        depths: impl Iterator<Item = u32>,
+        mut report: impl FnMut(&Self, &mut SearchInfo),
+    ) -> Option<HalfMove> {
+        let mut best_move = None;
+        for depth in depths {
+            let mut pv = Vec::new();
+            let score = self.alpha_beta(board, depth, -INFINITY, INFINITY, 0, &mut pv);
+            // An unfinished iteration has not looked at every move, so its choice cannot be
+            // trusted.
+            if self.aborted || pv.is_empty() {
+                break;
+            }
+
+            best_move = pv.first().copied();
+            let mut info = SearchInfo {
+                depth,
+                score,
+                nodes: self.nodes,
+                elapsed: self.start.elapsed(),
+                pv: pv.clone(),
+                hashfull: self.table.hashfull(),
+            };
+            report(self, &mut info);
+            self.previous_pv = pv;
+            if info.mate_in().is_some() {
+                break;
+            }
+            if let Some(time) = &mut self.time {
+                time.update(info.pv[0], score);
+                if time.soft_limit_reached() {
+                    break;
+                }
+            }
+        }
+        best_move
+    }
+
+    /// Fail-soft negamax alpha-beta. Fills `pv` with the best line found when the score lands
+    /// inside the window. The root is never cut off by the table, so it always has a move.
+    fn alpha_beta(
+        &mut self,
+        board: &mut Board,
+        depth: u32,
+        mut alpha: i32,
+        beta: i32,
+        ply: u32,
+        pv: &mut Vec<HalfMove>,
+    ) -> i32 {
+        pv.clear();
+        if self.visit() {
+            return 0;
+        }
+        if ply > 0 && Self::is_draw(board) {
+            return 0;
+        }
+        let moves = board.legal_moves();
+        if moves.is_empty() {
+            return Self::no_moves_score(board, ply);
+        }
+        if depth == 0 || ply >= MAX_PLY {
+            return self.quiescence(board, alpha, beta, ply, pv);
+        }
+
+        let entry = self.table.probe(board.hash(), ply);
+        if let Some(entry) = entry.filter(|entry| ply > 0 && entry.depth >= depth) {
+            let cutoff = match entry.bound {
+                Bound::Exact => true,
+                Bound::Lower => entry.score >= beta,
+                Bound::Upper => entry.score <= alpha,
+            };
+            if cutoff {
+                return entry.score;
+            }
+        }
+
+        let original_alpha = alpha;
+        let mut best_score = -INFINITY;
+        let mut best_move = None;
+        let mut child_pv = Vec::new();
+        let table_move = entry.and_then(|entry| entry.best_move);
+        for half_move in self.ordered_moves(board, &moves, ply, table_move) {
+            let undo = self.make_move(board, &half_move);
+            let score = -self.alpha_beta(board, depth - 1, -beta, -alpha, ply + 1, &mut child_pv);
+            self.unmake_move(board, &half_move, &undo);
+            if self.aborted {
+                return 0;
+            }
+
+            if score > best_score {
+                best_score = score;
+                best_move = Some(half_move);
+            }
+            if score > alpha {
+                alpha = score;
+                pv.clear();
+                pv.push(half_move);
+                pv.append(&mut child_pv);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best_score >= beta {
+            Bound::Lower
+        } else if best_score > original_alpha {
+            Bound::Exact
+        } else {
+            Bound::Upper
+        };
+        self.table.store(
+            board.hash(),
+            ply,
+            &Entry {
+                depth,
+                bound,
+                score: best_score,
+                best_move,
+            },
+        );
+        best_score
+    }
+
+    /// Resolves captures and promotions until the position is quiet, so the static evaluation
+    /// is never taken in the middle of an exchange. In check, every evasion is searched. Fills
+    /// `pv` like [`Searcher::alpha_beta`].
+    fn quiescence(
+        &mut self,
+        board: &mut Board,
+        mut alpha: i32,
+        beta: i32,
+        ply: u32,
+        pv: &mut Vec<HalfMove>,
+    ) -> i32 {
+        pv.clear();
+        if self.visit() {
+            return 0;
+        }
+        let moves = board.legal_moves();
+        if moves.is_empty() {
+            return Self::no_moves_score(board, ply);
+        }
+        let in_check = board.is_check();
+        if ply >= MAX_PLY {
+            return self.evaluate(board);
+        }
+
+        let mut best_score = -INFINITY;
+        if !in_check {
+            best_score = self.evaluate(board);
+            if best_score >= beta {
+                return best_score;
+            }
+            alpha = alpha.max(best_score);
+        }
+
+        let mut child_pv = Vec::new();
+        for half_move in self.ordered_moves(board, &moves, ply, None) {
+            if !in_check && !is_tactical(board, &half_move) {
+                continue;
+            }
+            let undo = self.make_move(board, &half_move);
+            let score = -self.quiescence(board, -beta, -alpha, ply + 1, &mut child_pv);
+            self.unmake_move(board, &half_move, &undo);
+            if self.aborted {
+                return 0;
+            }
+
+            best_score = best_score.max(score);
+            if score > alpha {
+                alpha = score;
+                pv.clear();
+                pv.push(half_move);
+                pv.append(&mut child_pv);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+        best_score
+    }
+
+    fn make_move(&mut self, board: &mut Board, half_move: &HalfMove) -> UndoInfo {
+        match &mut self.evaluator {
+            Some(evaluator) => evaluator.make_move(board, half_move),
+            None => board.make_move(half_move),
+        }
+    }
+
+    fn unmake_move(&mut self, board: &mut Board, half_move: &HalfMove, undo: &UndoInfo) {
+        match &mut self.evaluator {
+            Some(evaluator) => evaluator.unmake_move(board, half_move, undo),
+            None => board.unmake_move(half_move, undo),
+        }
+    }
+
+    fn evaluate(&self, board: &Board) -> i32 {
+        self.evaluator.as_ref().map_or_else(
+            || eval::evaluate(board),
+            |evaluator| evaluator.evaluate(board),
+        )
+    }
+
+    /// Counts a node and reports whether the search has to stop.
+    fn visit(&mut self) -> bool {
+        self.nodes += 1;
+        if self.nodes % 1024 == 0 {
+            self.node_count.store(self.nodes, Ordering::Relaxed);
+        }
+        if !self.aborted && self.should_abort() {
+            self.aborted = true;
+        }
+        self.aborted
+    }
+
+    /// Checks the stop flag and the node limit at every node, and the clock every 1024 nodes.
+    fn should_abort(&self) -> bool {
+        self.stop.load(Ordering::Relaxed)
+            || self.limits.nodes.is_some_and(|nodes| self.nodes >= nodes)
+            || (self.nodes % 1024 == 0
+                && self
+                    .time
+                    .as_ref()
+                    .is_some_and(TimeManager::hard_limit_reached))
+    }
+
+    /// Repetitions count as draws from their second occurrence, since repeating once is enough
+    /// to show neither side can make progress.
+    fn is_draw(board: &Board) -> bool {
+        board.repetition_count() > 1
+            || board.claimable_draw().is_some()
+            || board.is_insufficient_material()
+    }
+
+    fn no_moves_score(board: &Board, ply: u32) -> i32 {
+        if board.is_check() {
+            -(MATE - ply.cast_signed())
+        } else {
+            0
+        }
+    }
+
+    /// Orders moves so the likeliest cutoffs come first: the transposition table's best move,
+    /// the previous principal variation's move at this ply, then captures and promotions by
+    /// most valuable victim and least valuable attacker, then quiet moves.
+    fn ordered_moves(
+        &self,
+        board: &Board,
+        moves: &[HalfMove],
+        ply: u32,
+        table_move: Option<HalfMove>,
+    ) -> Vec<HalfMove> {
+        let pv_move = self.previous_pv.get(ply as usize);
+        let mut ordered = moves.to_vec();
+        ordered.sort_by_cached_key(|half_move| {
+            if table_move.is_some_and(|table_move| table_move.same_squares(half_move)) {
+                i32::MIN
+            } else if pv_move == Some(half_move) {
+                i32::MIN + 1
+            } else {
+                -capture_order(board, half_move)
+            }
+        });
+        ordered
+    }
+}
+
+/// Whether a move changes the material balance.
+fn is_tactical(board: &Board, half_move: &HalfMove) -> bool {
+    half_move.promotion().is_some()
+        || half_move.kind() == MoveKind::EnPassant
+        || board.get(*half_move.to()).is_some()
+}
+
+/// MVV-LVA ordering key for captures and promotions; zero for quiet moves.
+fn capture_order(board: &Board, half_move: &HalfMove) -> i32 {
+    let victim = if half_move.kind() == MoveKind::EnPassant {
+        Some(&PAWN)
+    } else {
+        board.get(*half_move.to()).map(|piece| piece.ptype())
+    };
+    let promotion = half_move
+        .promotion()
+        .map_or(0, |ptype| PIECE_VALUES[ptype.index()]);
+    let attacker = board
+        .get(*half_move.from())
+        .map_or(0, |piece| PIECE_VALUES[piece.ptype().index()]);
+    victim.map_or(promotion, |victim| {
+        promotion + 10 * PIECE_VALUES[victim.index()] - attacker / 10 + 1
+    })
+}
+
+/// Plays each move with a fixed set of search limits.
+pub struct SearchPlayer {
+    limits: SearchLimits,
+    table: Arc<TranspositionTable>,
+    network: Option<Arc<Network>>,
+}
+
+impl SearchPlayer {
+    /// A player with a transposition table of its own, of the default size.
+    pub fn new(limits: SearchLimits) -> Self {
+        Self::with_table(limits, Arc::default())
+    }
+
+    /// A player sharing `table` with other players or searches.
+    pub const fn with_table(limits: SearchLimits, table: Arc<TranspositionTable>) -> Self {
+        Self {
+            limits,
+            table,
+            network: None,
+        }
+    }
+
+    /// Evaluates positions with `network` instead of the hand-crafted evaluation.
+    #[must_use]
+    pub fn with_network(self, network: Arc<Network>) -> Self {
+        Self {
+            network: Some(network),
+            ..self
+        }
+    }
+
+    pub fn table(&self) -> &TranspositionTable {
+        &self.table
+    }
+}
+
+impl Player for SearchPlayer {
+    /// Searches within the player's limits, and in a timed game also within the time the
+    /// [`TimeManager`] gives this move, stopping early once [`Turn::stop`] is raised. Takes a
+    /// draw, by claim or by accepting an offer, only when the search thinks it is losing.
+    fn act(&self, turn: &Turn) -> Action {
+        let board = turn.board;
+        let mut limits = self.limits;
+        if let Some(clock) = turn.clock {
+            let color = board.current_turn();
+            let control = clock.control();
+            limits.time_left = Some(clock.remaining(color));
+            // A delay is time the clock does not charge, much like an increment.
+            limits.increment =
+                control.increment + control.delay.map_or(Duration::ZERO, Delay::duration);
+            limits.moves_to_go = clock.moves_to_go(color);
+        }
+        let mut score = 0;
+        let best_move = search(
+            board,
+            &limits,
+            &self.table,
+            self.network.as_deref(),
+            turn.stop,
+            |info| score = info.score,
+        )
+        .expect("the game asks for a move only while legal moves remain");
+
+        if score < 0 && board.claimable_draw().is_some() {
+            Action::ClaimDraw
+        } else if score < 0 && turn.draw_offered {
+            Action::AcceptDraw
+        } else {
+            Action::Move(best_move)
+        }
+    }
+
+    fn new_game(&self) {
+        self.table.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITALIAN: &str = "r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R b KQkq - 3 3";
+
+    /// Searches `fen` with a fresh table, returning the best move and the last report.
+    fn run_with(fen: &str, limits: &SearchLimits) -> (Option<HalfMove>, SearchInfo) {
+        let board = Board::from_fen(fen).expect("test FEN strings are valid");
+        let table = TranspositionTable::new(1);
+        let stop = AtomicBool::new(false);
+        let mut last = None;
+        let best_move = search(&board, limits, &table, None, &stop, |info| {
+            last = Some(info.clone());
+        });
+        (best_move, last.expect("at least one iteration completes"))
+    }
+
+    /// Searches `fen` to `depth` on the default number of threads.
+    fn run(fen: &str, depth: u32) -> (Option<HalfMove>, SearchInfo) {
+        let limits = SearchLimits {
+            depth: Some(depth),
+            ..SearchLimits::default()
+        };
+        run_with(fen, &limits)
+    }
+
+    fn uci(half_move: Option<HalfMove>) -> Option<String> {
+        half_move.map(|half_move| half_move.to_string())
+    }
+
+    #[test]
+    fn single_thread_depth_limited_search_is_deterministic() {
+        let limits = SearchLimits {
+            depth: Some(3),
+            threads: 1,
+            ..SearchLimits::default()
+        };
+        let (first_move, first) = run_with(ITALIAN, &limits);
+        let (second_move, second) = run_with(ITALIAN, &limits);
+        assert_eq!(first_move, second_move);
+        assert_eq!(first.pv, second.pv);
+        assert_eq!(first.score, second.score);
+        assert_eq!(first.nodes, second.nodes);
+        assert_eq!(first.depth, 3);
+    }
+
+    #[test]
+    fn single_thread_node_limited_search_is_deterministic() {
+        let limits = SearchLimits {
+            nodes: Some(5_000),
+            threads: 1,
+            ..SearchLimits::default()
+        };
+        let (first_move, first) = run_with(ITALIAN, &limits);
+        let (second_move, second) = run_with(ITALIAN, &limits);
+        assert_eq!(first_move, second_move);
+        assert_eq!(first.pv, second.pv);
+        assert_eq!(first.nodes, second.nodes);
+    }
+
+    #[test]
+    fn helper_threads_return_a_legal_move() {
+        let limits = SearchLimits {
+            depth: Some(3),
+            threads: 4,
+            ..SearchLimits::default()
+        };
+        let board = Board::from_fen(ITALIAN).unwrap();
+        let (best_move, info) = run_with(ITALIAN, &limits);
+        let best_move = best_move.expect("the position has legal moves");
+        assert!(board.legal_moves().contains(&best_move));
+        assert_eq!(info.pv.first(), Some(&best_move));
+    }
+
+    #[test]
+    fn finds_mate_in_one() {
+        let (best_move, info) = run("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", 3);
+        assert_eq!(uci(best_move), Some("a1a8".to_string()));
+        assert_eq!(info.mate_in(), Some(1));
+    }
+
+    #[test]
+    fn finds_mate_in_two() {
+        let (best_move, info) = run("k7/8/8/8/8/8/7R/4K1R1 w - - 0 1", 5);
+        assert_eq!(info.mate_in(), Some(2));
+        assert_eq!(uci(info.pv.first().copied()), uci(best_move));
+        assert_eq!(info.pv.len(), 3);
+    }
+
+    #[test]
+    fn sees_being_mated() {
+        let (best_move, info) = run("k7/8/1K6/8/8/8/8/7R b - - 0 1", 3);
+        assert_eq!(uci(best_move), Some("a8b8".to_string()));
+        assert_eq!(info.mate_in(), Some(-1));
+    }
+
+    #[test]
+    fn quiescence_takes_hanging_pieces() {
+        let (best_move, info) = run("4k3/8/8/3r4/8/8/8/3QK3 w - - 0 1", 1);
+        assert_eq!(uci(best_move), Some("d1d5".to_string()));
+        assert!(info.score >= 800, "{}", info.score);
+    }
+
+    #[test]
+    fn quiescence_does_not_take_defended_pawns_with_the_queen() {
+        let (best_move, info) = run("4k3/8/4p3/3p4/8/8/8/3QK3 w - - 0 1", 1);
+        assert_ne!(uci(best_move), Some("d1d5".to_string()));
+        assert!(info.score >= 600, "{}", info.score);
+    }
+
+    #[test]
+    fn draws_score_zero() {
+        // A rook up, but every move reaches the fifty-move rule.
+        let (_, info) = run("4k3/8/8/8/8/8/8/R3K3 w - - 99 80", 3);
+        assert_eq!(info.score, 0);
+        let (_, info) = run("4k3/8/8/8/8/8/8/1N2K3 w - - 0 1", 3);
+        assert_eq!(info.score, 0);
+    }
+
+    #[test]
+    fn stalemate_has_no_best_move() {
+        let board = Board::from_fen("k7/8/1Q6/8/8/8/8/K7 b - - 0 1").unwrap();
+        let limits = SearchLimits::default();
+        let table = TranspositionTable::new(1);
+        assert!(search(
+            &board,
+            &limits,
+            &table,
+            None,
+            &AtomicBool::new(false),
+            |_| {}
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,293 @@
+use std::time::{Duration, Instant};
+
+use crate::half_move::HalfMove;
+
+/// How many moves the remaining time is spread over when the GUI does not say.
+const DEFAULT_MOVES_TO_GO: u32 = 30;
+/// How far past the soft limit a search may run before it is cut off, unless the clock says
+/// otherwise.
+const HARD_LIMIT_FACTOR: u32 = 5;
+/// The share of the remaining time a single move may use at most, in percent.
+const MAX_CLOCK_SHARE: u32 = 75;
+/// A score drop of this many centipawns or more doubles the soft limit.
+const MAX_SCORE_DROP: i32 = 100;
+
+/// The clock settings a move's time is budgeted from. Every limit that is set applies.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct TimeLimits {
+    pub movetime: Option<Duration>,
+    /// Time left on the clock of the side to move.
+    pub time_left: Option<Duration>,
+    pub increment: Duration,
+    pub moves_to_go: Option<u32>,
+    /// Time kept back from every move for communication delays.
+    pub move_overhead: Duration,
+}
+
+/// Decides how long a move may take, from the clock and from how settled the search is.
+///
+/// The soft limit is checked between iterations: once it has passed, starting another depth is
+/// not worth it. It stretches while the best move keeps changing or the score is falling, and
+/// shrinks back as the search settles. The hard limit is checked while searching and ends the
+/// search at once.
+#[derive(Clone)]
+pub struct TimeManager {
+    start: Instant,
+    /// The soft limit for a perfectly stable search.
+    optimum: Duration,
+    hard: Duration,
+    /// Recent best-move changes, halved every iteration so that older changes count less.
+    instability: f64,
+    /// How far the score fell in the last iteration, in centipawns.
+    score_drop: i32,
+    best_move: Option<HalfMove>,
+    score: Option<i32>,
+}
+
+impl TimeManager {
+    /// Starts the clock for a move searched within `limits`, or returns `None` if they do not
+    /// limit its time.
+    pub fn new(limits: &TimeLimits) -> Option<Self> {
+        let from_clock = limits.time_left.map(|left| {
+            let left = left.saturating_sub(limits.move_overhead);
+            let moves_to_go = limits.moves_to_go.unwrap_or(DEFAULT_MOVES_TO_GO).max(1);
+            let ceiling = left * MAX_CLOCK_SHARE / 100;
+            let optimum = (left / moves_to_go + limits.increment * 3 / 4).min(ceiling);
+            (optimum, (optimum * HARD_LIMIT_FACTOR).min(ceiling))
+        });
+        // A fixed move time is spent in full whatever the search does.
+        let fixed = limits
+            .movetime
+            .map(|movetime| movetime.saturating_sub(limits.move_overhead))
+            .map(|movetime| (movetime, movetime));
+        let (optimum, hard) = match (fixed, from_clock) {
+            (Some(fixed), Some(from_clock)) => {
+                (fixed.0.min(from_clock.0), fixed.1.min(from_clock.1))
+            }
+            (fixed, from_clock) => fixed.or(from_clock)?,
+        };
+        Some(Self {
+            start: Instant::now(),
+            optimum,
+            hard,
+            instability: 0.0,
+            score_drop: 0,
+            best_move: None,
+            score: None,
+        })
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// When to stop deepening, given how stable the search has been so far. Never past the
+    /// hard limit.
+    pub fn soft_limit(&self) -> Duration {
+        let changes = 1.0 + self.instability / 2.0;
+        let drop =
+            1.0 + f64::from(self.score_drop.clamp(0, MAX_SCORE_DROP)) / f64::from(MAX_SCORE_DROP);
+        self.optimum.mul_f64(changes * drop).min(self.hard)
+    }
+
+    pub const fn hard_limit(&self) -> Duration {
+        self.hard
+    }
+
+    /// Records the best move and score of an iteration that just completed.
+    pub fn update(&mut self, best_move: HalfMove, score: i32) {
+        self.instability /= 2.0;
+        if self.best_move.is_some_and(|previous| previous != best_move) {
+            self.instability += 1.0;
+        }
+        self.best_move = Some(best_move);
+        self.score_drop = self.score.map_or(0, |previous| previous - score);
+        self.score = Some(score);
+    }
+
+    /// Whether the soft limit has passed, so the next iteration should not start.
+    pub fn soft_limit_reached(&self) -> bool {
+        self.elapsed() >= self.soft_limit()
+    }
+
+    /// Whether the hard limit has passed, so the search should stop at once.
+    pub fn hard_limit_reached(&self) -> bool {
+        self.elapsed() >= self.hard
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::SearchLimits;
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn clock(time_left: u64, increment: u64) -> TimeLimits {
+        TimeLimits {
+            time_left: Some(millis(time_left)),
+            increment: millis(increment),
+            ..TimeLimits::default()
+        }
+    }
+
+    /// The optimum and hard limits for `limits`.
+    fn budget(limits: &TimeLimits) -> (Duration, Duration) {
+        let time = TimeManager::new(limits).expect("the limits bound the time");
+        (time.soft_limit(), time.hard_limit())
+    }
+
+    #[test]
+    fn spreads_the_clock_over_the_remaining_moves() {
+        assert_eq!(budget(&clock(60_000, 0)), (millis(2000), millis(10_000)));
+        assert_eq!(budget(&clock(60_000, 1000)), (millis(2750), millis(13_750)));
+
+        let tournament = TimeLimits {
+            moves_to_go: Some(10),
+            ..clock(60_000, 1000)
+        };
+        assert_eq!(budget(&tournament), (millis(6750), millis(33_750)));
+
+        // The last move before the time control may use up to the clock share, and no more.
+        let last_move = TimeLimits {
+            moves_to_go: Some(1),
+            ..clock(60_000, 0)
+        };
+        assert_eq!(budget(&last_move), (millis(45_000), millis(45_000)));
+        let zero = TimeLimits {
+            moves_to_go: Some(0),
+            ..clock(60_000, 0)
+        };
+        assert_eq!(budget(&zero), budget(&last_move));
+    }
+
+    #[test]
+    fn never_plans_past_the_remaining_time() {
+        // A large increment cannot be spent before it arrives.
+        assert_eq!(budget(&clock(1000, 5000)), (millis(750), millis(750)));
+
+        for time_left in [0, 1, 9, 10, 11, 50, 1000, 10_000, 600_000] {
+            for increment in [0, 100, 2000, 30_000] {
+                for overhead in [0, 10, 50, 5000] {
+                    for moves_to_go in [None, Some(0), Some(1), Some(5), Some(40)] {
+                        let limits = TimeLimits {
+                            moves_to_go,
+                            move_overhead: millis(overhead),
+                            ..clock(time_left, increment)
+                        };
+                        let (soft, hard) = budget(&limits);
+                        let usable = millis(time_left).saturating_sub(millis(overhead));
+                        assert!(soft <= hard, "{limits:?}");
+                        assert!(hard <= usable, "{limits:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_back_the_move_overhead() {
+        let limits = TimeLimits {
+            move_overhead: millis(30),
+            ..clock(3030, 0)
+        };
+        assert_eq!(budget(&limits), (millis(100), millis(500)));
+
+        // An overhead larger than the clock leaves nothing rather than going negative.
+        let flagging = TimeLimits {
+            move_overhead: millis(50),
+            ..clock(20, 1000)
+        };
+        assert_eq!(budget(&flagging), (Duration::ZERO, Duration::ZERO));
+        let fixed = TimeLimits {
+            movetime: Some(millis(5)),
+            move_overhead: millis(10),
+            ..TimeLimits::default()
+        };
+        assert_eq!(budget(&fixed), (Duration::ZERO, Duration::ZERO));
+    }
+
+    #[test]
+    fn spends_a_fixed_move_time_in_full() {
+        let fixed = TimeLimits {
+            movetime: Some(millis(2000)),
+            move_overhead: millis(10),
+            ..TimeLimits::default()
+        };
+        assert_eq!(budget(&fixed), (millis(1990), millis(1990)));
+
+        // A clock that cannot afford the move time still wins.
+        let short_clock = TimeLimits {
+            movetime: Some(millis(2000)),
+            ..clock(1000, 0)
+        };
+        let optimum = millis(1000) / DEFAULT_MOVES_TO_GO;
+        assert_eq!(budget(&short_clock), (optimum, optimum * HARD_LIMIT_FACTOR));
+    }
+
+    #[test]
+    fn infinite_and_depth_only_searches_have_no_deadline() {
+        let infinite = SearchLimits {
+            infinite: true,
+            time_left: Some(millis(1000)),
+            movetime: Some(millis(100)),
+            ..SearchLimits::default()
+        };
+        assert!(infinite.time_limits().is_none());
+
+        let depth_only = SearchLimits {
+            depth: Some(8),
+            nodes: Some(1000),
+            ..SearchLimits::default()
+        };
+        let limits = depth_only
+            .time_limits()
+            .expect("only infinite searches have none");
+        assert!(TimeManager::new(&limits).is_none());
+        assert!(TimeManager::new(&TimeLimits::default()).is_none());
+
+        // An increment alone says nothing about the time left.
+        let increment_only = TimeLimits {
+            increment: millis(1000),
+            ..TimeLimits::default()
+        };
+        assert!(TimeManager::new(&increment_only).is_none());
+    }
+
+    #[test]
+    fn stretches_the_soft_limit_while_unsettled() {
+        let mut time = TimeManager::new(&clock(60_000, 0)).unwrap();
+        let (e4, d4): (HalfMove, HalfMove) = ("e2e4".parse().unwrap(), "d2d4".parse().unwrap());
+        time.update(e4, 20);
+        time.update(e4, 25);
+        assert_eq!(time.soft_limit(), millis(2000));
+
+        // A new best move counts for half as much again.
+        time.update(d4, 25);
+        assert_eq!(time.soft_limit(), millis(3000));
+        // And fades once the best move settles.
+        time.update(d4, 25);
+        assert_eq!(time.soft_limit(), millis(2500));
+
+        // A score drop of a pawn or more doubles the limit on top.
+        time.update(d4, -200);
+        assert_eq!(time.soft_limit(), millis(4500));
+
+        // But never past the hard limit, here the share of the clock one move may take.
+        let mut time = TimeManager::new(&TimeLimits {
+            moves_to_go: Some(3),
+            ..clock(60_000, 0)
+        })
+        .unwrap();
+        assert_eq!(
+            (time.soft_limit(), time.hard_limit()),
+            (millis(20_000), millis(45_000))
+        );
+        for score in 0..20 {
+            time.update(if score % 2 == 0 { e4 } else { d4 }, -1000 * score);
+        }
+        assert_eq!(time.soft_limit(), millis(45_000));
+    }
+}
@@ -0,0 +1,425 @@
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+use crate::{
+    half_move::HalfMove,
+    pieces::{PieceType, PIECE_TYPES},
+    position::Position,
+};
+
+use super::{MATE, MAX_PLY};
+
+const SLOTS_PER_BUCKET: usize = 4;
+const BUCKET_BYTES: usize = std::mem::size_of::<Bucket>();
+/// Generations are stored in six bits and wrap around.
+const GENERATION_MASK: u8 = 0x3f;
+
+/// How a stored score relates to the true score of the position.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Bound {
+    /// The true score is at most this; every move failed low.
+    Upper = 1,
+    /// The true score is at least this; a move failed high.
+    Lower = 2,
+    Exact = 3,
+}
+
+/// What the table remembers about a position.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub depth: u32,
+    pub bound: Bound,
+    /// The score relative to the probing node, with mate distances already adjusted.
+    pub score: i32,
+    /// The origin, destination and promotion of the best move, without its
+    /// [`MoveKind`](crate::half_move::MoveKind); match it against the legal moves with
+    /// [`HalfMove::same_squares`].
+    pub best_move: Option<HalfMove>,
+}
+
+/// Probe and store counters since the table was created or last cleared.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct TableStats {
+    pub probes: u64,
+    pub hits: u64,
+    pub stores: u64,
+}
+
+impl TableStats {
+    // Counts stay far below 2^52, where the conversion would start rounding.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn hit_rate(&self) -> f64 {
+        if self.probes == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.probes as f64
+        }
+    }
+}
+
+/// A slot holds the entry's packed data and the position hash xored with it. Both halves are
+/// written without locking, so a reader that sees one thread's key and another's data finds
+/// that they do not xor back to its hash and treats the slot as a miss.
+#[derive(Default)]
+struct Slot {
+    key: AtomicU64,
+    data: AtomicU64,
+}
+
+#[derive(Default)]
+#[repr(align(64))]
+struct Bucket {
+    slots: [Slot; SLOTS_PER_BUCKET],
+}
+
+/// A fixed-size hash table of search results shared by every search thread, keyed by
+/// [`Board::hash`](crate::board::Board::hash).
+pub struct TranspositionTable {
+    buckets: Box<[Bucket]>,
+    generation: AtomicU8,
+    probes: AtomicU64,
+    hits: AtomicU64,
+    stores: AtomicU64,
+}
+
+impl TranspositionTable {
+    pub const DEFAULT_MEGABYTES: usize = 16;
+
+    /// Allocates a table using about `megabytes` of memory, and at least one bucket.
+    pub fn new(megabytes: usize) -> Self {
+        let buckets = (megabytes * 1024 * 1024 / BUCKET_BYTES).max(1);
+        Self {
+            buckets: (0..buckets).map(|_| Bucket::default()).collect(),
+            generation: AtomicU8::new(0),
+            probes: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            stores: AtomicU64::new(0),
+        }
+    }
+
+    /// Forgets every entry and resets the statistics, as at the start of a new game.
+    pub fn clear(&self) {
+        for slot in self.buckets.iter().flat_map(|bucket| &bucket.slots) {
+            slot.key.store(0, Ordering::Relaxed);
+            slot.data.store(0, Ordering::Relaxed);
+        }
+        self.generation.store(0, Ordering::Relaxed);
+        self.probes.store(0, Ordering::Relaxed);
+        self.hits.store(0, Ordering::Relaxed);
+        self.stores.store(0, Ordering::Relaxed);
+    }
+
+    /// Marks entries from earlier searches as stale, so they are replaced first.
+    pub fn new_search(&self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn probe(&self, hash: u64, ply: u32) -> Option<Entry> {
+        self.probes.fetch_add(1, Ordering::Relaxed);
+        let entry = self.bucket(hash).slots.iter().find_map(|slot| {
+            let data = slot.data.load(Ordering::Relaxed);
+            let key = slot.key.load(Ordering::Relaxed);
+            (data != 0 && key ^ data == hash).then(|| unpack(data, ply))
+        })?;
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(entry)
+    }
+
+    /// Stores a search result for the position with `hash`, found `ply` moves from the root.
+    /// Replaces the entry for the same position if there is one, and otherwise the shallowest
+    /// entry, counting entries from earlier searches as shallower.
+    pub fn store(&self, hash: u64, ply: u32, entry: &Entry) {
+        let generation = self.generation();
+        let bucket = self.bucket(hash);
+        let slot = bucket
+            .slots
+            .iter()
+            .find(|slot| {
+                let data = slot.data.load(Ordering::Relaxed);
+                data == 0 || slot.key.load(Ordering::Relaxed) ^ data == hash
+            })
+            .or_else(|| {
+                bucket.slots.iter().min_by_key(|slot| {
+                    let data = slot.data.load(Ordering::Relaxed);
+                    let age = generation.wrapping_sub(stored_generation(data)) & GENERATION_MASK;
+                    stored_depth(data) - 8 * i32::from(age)
+                })
+            })
+            .expect("buckets are never empty");
+
+        let data = pack(entry, ply, generation);
+        slot.key.store(hash ^ data, Ordering::Relaxed);
+        slot.data.store(data, Ordering::Relaxed);
+        self.stores.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// How full the table is in permille, estimated from the first thousand slots, as UCI's
+    /// `hashfull` expects. Only entries from the current search count.
+    pub fn hashfull(&self) -> u32 {
+        let generation = self.generation();
+        let sample = self
+            .buckets
+            .iter()
+            .flat_map(|bucket| &bucket.slots)
+            .take(1000);
+        let (mut total, mut used) = (0, 0);
+        for slot in sample {
+            let data = slot.data.load(Ordering::Relaxed);
+            total += 1;
+            if data != 0 && stored_generation(data) == generation {
+                used += 1;
+            }
+        }
+        used * 1000 / total
+    }
+
+    pub fn stats(&self) -> TableStats {
+        TableStats {
+            probes: self.probes.load(Ordering::Relaxed),
+            hits: self.hits.load(Ordering::Relaxed),
+            stores: self.stores.load(Ordering::Relaxed),
+        }
+    }
+
+    fn generation(&self) -> u8 {
+        self.generation.load(Ordering::Relaxed) & GENERATION_MASK
+    }
+
+    /// Maps the hash onto the bucket range with a multiply and shift, so the table does not
+    /// need a power-of-two size.
+    fn bucket(&self, hash: u64) -> &Bucket {
+        // The product shifted right by 64 is below the bucket count, so it fits in a usize.
+        #[allow(clippy::cast_possible_truncation)]
+        let index = ((u128::from(hash) * self.buckets.len() as u128) >> 64) as usize;
+        &self.buckets[index]
+    }
+}
+
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MEGABYTES)
+    }
+}
+
+/// Mate scores are stored as distances from the stored position rather than from the root, so
+/// they stay correct when the position is reached at a different ply.
+const fn score_to_table(score: i32, ply: u32) -> i32 {
+    let ply = ply.cast_signed();
+    if score > MATE - MAX_PLY.cast_signed() {
+        score + ply
+    } else if score < MAX_PLY.cast_signed() - MATE {
+        score - ply
+    } else {
+        score
+    }
+}
+
+const fn score_from_table(score: i32, ply: u32) -> i32 {
+    let ply = ply.cast_signed();
+    if score > MATE - MAX_PLY.cast_signed() {
+        score - ply
+    } else if score < MAX_PLY.cast_signed() - MATE {
+        score + ply
+    } else {
+        score
+    }
+}
+
+// Packed entry layout, from the lowest bit: move (16), score (16), depth (8), bound (2) and
+// generation (6). A zero word is an empty slot, which the nonzero bound rules out for entries.
+
+fn pack(entry: &Entry, ply: u32, generation: u8) -> u64 {
+    let best_move = entry.best_move.map_or(0, |half_move| pack_move(&half_move));
+    let score = i16::try_from(score_to_table(entry.score, ply)).expect("scores fit in an i16");
+    let depth = u8::try_from(entry.depth.min(u32::from(u8::MAX))).expect("depth is clamped");
+    u64::from(best_move)
+        | u64::from(score.cast_unsigned()) << 16
+        | u64::from(depth) << 32
+        | (entry.bound as u64) << 40
+        | u64::from(generation) << 42
+}
+
+// Each field is masked to its width before narrowing, so none of these casts lose bits.
+#[allow(clippy::cast_possible_truncation)]
+fn unpack(data: u64, ply: u32) -> Entry {
+    let score = i32::from(((data >> 16) as u16).cast_signed());
+    Entry {
+        depth: u32::from((data >> 32) as u8),
+        bound: match (data >> 40) & 0b11 {
+            1 => Bound::Upper,
+            2 => Bound::Lower,
+            _ => Bound::Exact,
+        },
+        score: score_from_table(score, ply),
+        best_move: unpack_move(data as u16),
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn stored_generation(data: u64) -> u8 {
+    (data >> 42) as u8 & GENERATION_MASK
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn stored_depth(data: u64) -> i32 {
+    (data >> 32) as u8 as i32
+}
+
+/// Origin and destination as six-bit square indices, then the promotion's piece index.
+#[allow(clippy::cast_possible_truncation)]
+fn pack_move(half_move: &HalfMove) -> u16 {
+    let promotion = half_move.promotion().map_or(0, PieceType::index);
+    (half_move.from().index() | half_move.to().index() << 6 | promotion << 12) as u16
+}
+
+fn unpack_move(packed: u16) -> Option<HalfMove> {
+    let square = |shift: u16| Position::try_from(usize::from(packed >> shift & 0x3f)).ok();
+    let (from, to) = (square(0)?, square(6)?);
+    if from == to {
+        return None;
+    }
+    Some(match usize::from(packed >> 12) {
+        0 => HalfMove::new(from, to),
+        promotion => HalfMove::with_promotion(from, to, PIECE_TYPES[promotion]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A table with a single bucket, so every hash competes for the same four slots.
+    fn one_bucket() -> TranspositionTable {
+        let table = TranspositionTable::new(0);
+        assert_eq!(table.buckets.len(), 1);
+        table
+    }
+
+    fn entry(depth: u32, score: i32) -> Entry {
+        Entry {
+            depth,
+            bound: Bound::Exact,
+            score,
+            best_move: None,
+        }
+    }
+
+    fn half_move(uci: &str) -> HalfMove {
+        uci.parse().expect("test moves are valid")
+    }
+
+    #[test]
+    fn packing_round_trips_every_field() {
+        let moves = [
+            None,
+            Some(half_move("e2e4")),
+            Some(half_move("h7h8n")),
+            Some(half_move("a2a1q")),
+        ];
+        let scores = [
+            0,
+            1,
+            -1,
+            35,
+            -900,
+            MATE - MAX_PLY.cast_signed() - 1,
+            30_000,
+            -30_000,
+        ];
+        let bounds = [Bound::Upper, Bound::Lower, Bound::Exact];
+        for best_move in moves {
+            for score in scores {
+                for bound in bounds {
+                    for depth in [0, 1, 17, 255] {
+                        let original = Entry {
+                            depth,
+                            bound,
+                            score,
+                            best_move,
+                        };
+                        let unpacked = unpack(pack(&original, 0, 5), 0);
+                        assert_eq!(unpacked.depth, depth);
+                        assert_eq!(unpacked.bound, bound);
+                        assert_eq!(unpacked.score, score);
+                        assert_eq!(unpacked.best_move, best_move);
+                        assert_eq!(stored_generation(pack(&original, 0, 5)), 5);
+                    }
+                }
+            }
+        }
+        assert_eq!(unpack(pack(&entry(1000, 0), 0, 0), 0).depth, 255);
+    }
+
+    #[test]
+    fn mate_scores_are_stored_relative_to_the_position() {
+        let table = TranspositionTable::new(1);
+        // Mate found five plies from the root, at a node three plies deep.
+        table.store(1, 3, &entry(4, MATE - 5));
+        assert_eq!(table.probe(1, 3).unwrap().score, MATE - 5);
+        assert_eq!(table.probe(1, 7).unwrap().score, MATE - 9);
+        assert_eq!(table.probe(1, 0).unwrap().score, MATE - 2);
+
+        table.store(2, 2, &entry(4, 6 - MATE));
+        assert_eq!(table.probe(2, 2).unwrap().score, 6 - MATE);
+        assert_eq!(table.probe(2, 4).unwrap().score, 8 - MATE);
+
+        table.store(3, 10, &entry(4, 250));
+        assert_eq!(table.probe(3, 0).unwrap().score, 250);
+    }
+
+    #[test]
+    fn replaces_the_shallowest_entry() {
+        let table = one_bucket();
+        for hash in 1..=4 {
+            table.store(hash, 0, &entry(u32::try_from(hash).unwrap(), 0));
+        }
+        table.store(5, 0, &entry(5, 0));
+        assert!(table.probe(1, 0).is_none());
+        for hash in 2..=5 {
+            assert!(table.probe(hash, 0).is_some(), "{hash} was replaced");
+        }
+
+        // The same position is overwritten in place, even by a shallower result.
+        table.store(5, 0, &entry(1, 42));
+        let stored = table.probe(5, 0).unwrap();
+        assert_eq!((stored.depth, stored.score), (1, 42));
+        assert!(table.probe(4, 0).is_some());
+    }
+
+    #[test]
+    fn prefers_entries_from_the_current_search() {
+        let table = one_bucket();
+        for hash in 1..=4 {
+            table.store(hash, 0, &entry(u32::try_from(hash).unwrap() + 1, 0));
+        }
+        table.new_search();
+        table.store(10, 0, &entry(1, 0));
+        assert!(table.probe(1, 0).is_none());
+        // The stale entries are deeper, but still go before the fresh shallow one.
+        table.store(11, 0, &entry(1, 0));
+        assert!(table.probe(10, 0).is_some());
+        assert!(table.probe(2, 0).is_none());
+        assert!(table.probe(3, 0).is_some());
+    }
+
+    #[test]
+    fn misses_on_a_different_key() {
+        let table = one_bucket();
+        table.store(0xDEAD_BEEF, 0, &entry(3, 7));
+        assert!(table.probe(0xDEAD_BEEE, 0).is_none());
+        assert!(table.probe(0, 0).is_none());
+        assert!(table.probe(0xDEAD_BEEF, 0).is_some());
+
+        let stats = table.stats();
+        assert_eq!((stats.probes, stats.hits, stats.stores), (3, 1, 1));
+        assert!((stats.hit_rate() - 1.0 / 3.0).abs() < 1e-9);
+
+        table.clear();
+        assert!(table.probe(0xDEAD_BEEF, 0).is_none());
+    }
+
+    #[test]
+    fn hit_rate_is_zero_without_probes() {
+        assert!(TableStats::default().hit_rate().abs() < f64::EPSILON);
+        assert!(TranspositionTable::new(1).stats().hit_rate().abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,1355 @@
+//! Precomputed attack tables. Generated by `cargo run --bin gen_bitboards`, do not edit by hand.
+#![allow(clippy::unreadable_literal)]
+
+pub static KNIGHT_ATTACKS: [u64; 64] = [
+    0x20400, 0x50800, 0xa1100, 0x142200, 0x284400, 0x508800, 0xa01000, 0x402000,
+    0x2040004, 0x5080008, 0xa110011, 0x14220022, 0x28440044, 0x50880088, 0xa0100010, 0x40200020,
+    0x204000402, 0x508000805, 0xa1100110a, 0x1422002214, 0x2844004428, 0x5088008850, 0xa0100010a0, 0x4020002040,
+    0x20400040200, 0x50800080500, 0xa1100110a00, 0x142200221400, 0x284400442800, 0x508800885000, 0xa0100010a000, 0x402000204000,
+    0x2040004020000, 0x5080008050000, 0xa1100110a0000, 0x14220022140000, 0x28440044280000, 0x50880088500000, 0xa0100010a00000, 0x40200020400000,
+    0x204000402000000, 0x508000805000000, 0xa1100110a000000, 0x1422002214000000, 0x2844004428000000, 0x5088008850000000, 0xa0100010a0000000, 0x4020002040000000,
+    0x400040200000000, 0x800080500000000, 0x1100110a00000000, 0x2200221400000000, 0x4400442800000000, 0x8800885000000000, 0x100010a000000000, 0x2000204000000000,
+    0x4020000000000, 0x8050000000000, 0x110a0000000000, 0x22140000000000, 0x44280000000000, 0x88500000000000, 0x10a00000000000, 0x20400000000000,
+];
+
+pub static KING_ATTACKS: [u64; 64] = [
+    0x302, 0x705, 0xe0a, 0x1c14, 0x3828, 0x7050, 0xe0a0, 0xc040,
+    0x30203, 0x70507, 0xe0a0e, 0x1c141c, 0x382838, 0x705070, 0xe0a0e0, 0xc040c0,
+    0x3020300, 0x7050700, 0xe0a0e00, 0x1c141c00, 0x38283800, 0x70507000, 0xe0a0e000, 0xc040c000,
+    0x302030000, 0x705070000, 0xe0a0e0000, 0x1c141c0000, 0x3828380000, 0x7050700000, 0xe0a0e00000, 0xc040c00000,
+    0x30203000000, 0x70507000000, 0xe0a0e000000, 0x1c141c000000, 0x382838000000, 0x705070000000, 0xe0a0e0000000, 0xc040c0000000,
+    0x3020300000000, 0x7050700000000, 0xe0a0e00000000, 0x1c141c00000000, 0x38283800000000, 0x70507000000000, 0xe0a0e000000000, 0xc040c000000000,
+    0x302030000000000, 0x705070000000000, 0xe0a0e0000000000, 0x1c141c0000000000, 0x3828380000000000, 0x7050700000000000, 0xe0a0e00000000000, 0xc040c00000000000,
+    0x203000000000000, 0x507000000000000, 0xa0e000000000000, 0x141c000000000000, 0x2838000000000000, 0x5070000000000000, 0xa0e0000000000000, 0x40c0000000000000,
+];
+
+pub static PAWN_ATTACKS: [[u64; 64]; 2] = [
+    [
+        0x200, 0x500, 0xa00, 0x1400, 0x2800, 0x5000, 0xa000, 0x4000,
+        0x20000, 0x50000, 0xa0000, 0x140000, 0x280000, 0x500000, 0xa00000, 0x400000,
+        0x2000000, 0x5000000, 0xa000000, 0x14000000, 0x28000000, 0x50000000, 0xa0000000, 0x40000000,
+        0x200000000, 0x500000000, 0xa00000000, 0x1400000000, 0x2800000000, 0x5000000000, 0xa000000000, 0x4000000000,
+        0x20000000000, 0x50000000000, 0xa0000000000, 0x140000000000, 0x280000000000, 0x500000000000, 0xa00000000000, 0x400000000000,
+        0x2000000000000, 0x5000000000000, 0xa000000000000, 0x14000000000000, 0x28000000000000, 0x50000000000000, 0xa0000000000000, 0x40000000000000,
+        0x200000000000000, 0x500000000000000, 0xa00000000000000, 0x1400000000000000, 0x2800000000000000, 0x5000000000000000, 0xa000000000000000, 0x4000000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x2, 0x5, 0xa, 0x14, 0x28, 0x50, 0xa0, 0x40,
+        0x200, 0x500, 0xa00, 0x1400, 0x2800, 0x5000, 0xa000, 0x4000,
+        0x20000, 0x50000, 0xa0000, 0x140000, 0x280000, 0x500000, 0xa00000, 0x400000,
+        0x2000000, 0x5000000, 0xa000000, 0x14000000, 0x28000000, 0x50000000, 0xa0000000, 0x40000000,
+        0x200000000, 0x500000000, 0xa00000000, 0x1400000000, 0x2800000000, 0x5000000000, 0xa000000000, 0x4000000000,
+        0x20000000000, 0x50000000000, 0xa0000000000, 0x140000000000, 0x280000000000, 0x500000000000, 0xa00000000000, 0x400000000000,
+        0x2000000000000, 0x5000000000000, 0xa000000000000, 0x14000000000000, 0x28000000000000, 0x50000000000000, 0xa0000000000000, 0x40000000000000,
+    ],
+];
+
+pub static BETWEEN: [[u64; 64]; 64] = [
+    [
+        0x0, 0x0, 0x2, 0x6, 0xe, 0x1e, 0x3e, 0x7e,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x100, 0x0, 0x200, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x10100, 0x0, 0x0, 0x40200, 0x0, 0x0, 0x0, 0x0,
+        0x1010100, 0x0, 0x0, 0x0, 0x8040200, 0x0, 0x0, 0x0,
+        0x101010100, 0x0, 0x0, 0x0, 0x0, 0x1008040200, 0x0, 0x0,
+        0x10101010100, 0x0, 0x0, 0x0, 0x0, 0x0, 0x201008040200, 0x0,
+        0x1010101010100, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40201008040200,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x4, 0xc, 0x1c, 0x3c, 0x7c,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x200, 0x0, 0x400, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x20200, 0x0, 0x0, 0x80400, 0x0, 0x0, 0x0,
+        0x0, 0x2020200, 0x0, 0x0, 0x0, 0x10080400, 0x0, 0x0,
+        0x0, 0x202020200, 0x0, 0x0, 0x0, 0x0, 0x2010080400, 0x0,
+        0x0, 0x20202020200, 0x0, 0x0, 0x0, 0x0, 0x0, 0x402010080400,
+        0x0, 0x2020202020200, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x2, 0x0, 0x0, 0x0, 0x8, 0x18, 0x38, 0x78,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x200, 0x0, 0x400, 0x0, 0x800, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x40400, 0x0, 0x0, 0x100800, 0x0, 0x0,
+        0x0, 0x0, 0x4040400, 0x0, 0x0, 0x0, 0x20100800, 0x0,
+        0x0, 0x0, 0x404040400, 0x0, 0x0, 0x0, 0x0, 0x4020100800,
+        0x0, 0x0, 0x40404040400, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x4040404040400, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x6, 0x4, 0x0, 0x0, 0x0, 0x10, 0x30, 0x70,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x400, 0x0, 0x800, 0x0, 0x1000, 0x0, 0x0,
+        0x20400, 0x0, 0x0, 0x80800, 0x0, 0x0, 0x201000, 0x0,
+        0x0, 0x0, 0x0, 0x8080800, 0x0, 0x0, 0x0, 0x40201000,
+        0x0, 0x0, 0x0, 0x808080800, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x80808080800, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x8080808080800, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0xe, 0xc, 0x8, 0x0, 0x0, 0x0, 0x20, 0x60,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x800, 0x0, 0x1000, 0x0, 0x2000, 0x0,
+        0x0, 0x40800, 0x0, 0x0, 0x101000, 0x0, 0x0, 0x402000,
+        0x2040800, 0x0, 0x0, 0x0, 0x10101000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x101010101000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x10101010101000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x1e, 0x1c, 0x18, 0x10, 0x0, 0x0, 0x0, 0x40,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x1000, 0x0, 0x2000, 0x0, 0x4000,
+        0x0, 0x0, 0x81000, 0x0, 0x0, 0x202000, 0x0, 0x0,
+        0x0, 0x4081000, 0x0, 0x0, 0x0, 0x20202000, 0x0, 0x0,
+        0x204081000, 0x0, 0x0, 0x0, 0x0, 0x2020202000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x202020202000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x20202020202000, 0x0, 0x0,
+    ],
+    [
+        0x3e, 0x3c, 0x38, 0x30, 0x20, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x2000, 0x0, 0x4000, 0x0,
+        0x0, 0x0, 0x0, 0x102000, 0x0, 0x0, 0x404000, 0x0,
+        0x0, 0x0, 0x8102000, 0x0, 0x0, 0x0, 0x40404000, 0x0,
+        0x0, 0x408102000, 0x0, 0x0, 0x0, 0x0, 0x4040404000, 0x0,
+        0x20408102000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x404040404000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40404040404000, 0x0,
+    ],
+    [
+        0x7e, 0x7c, 0x78, 0x70, 0x60, 0x40, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x4000, 0x0, 0x8000,
+        0x0, 0x0, 0x0, 0x0, 0x204000, 0x0, 0x0, 0x808000,
+        0x0, 0x0, 0x0, 0x10204000, 0x0, 0x0, 0x0, 0x80808000,
+        0x0, 0x0, 0x810204000, 0x0, 0x0, 0x0, 0x0, 0x8080808000,
+        0x0, 0x40810204000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x808080808000,
+        0x2040810204000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80808080808000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x200, 0x600, 0xe00, 0x1e00, 0x3e00, 0x7e00,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x10000, 0x0, 0x20000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1010000, 0x0, 0x0, 0x4020000, 0x0, 0x0, 0x0, 0x0,
+        0x101010000, 0x0, 0x0, 0x0, 0x804020000, 0x0, 0x0, 0x0,
+        0x10101010000, 0x0, 0x0, 0x0, 0x0, 0x100804020000, 0x0, 0x0,
+        0x1010101010000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20100804020000, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x400, 0xc00, 0x1c00, 0x3c00, 0x7c00,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x20000, 0x0, 0x40000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x2020000, 0x0, 0x0, 0x8040000, 0x0, 0x0, 0x0,
+        0x0, 0x202020000, 0x0, 0x0, 0x0, 0x1008040000, 0x0, 0x0,
+        0x0, 0x20202020000, 0x0, 0x0, 0x0, 0x0, 0x201008040000, 0x0,
+        0x0, 0x2020202020000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40201008040000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x200, 0x0, 0x0, 0x0, 0x800, 0x1800, 0x3800, 0x7800,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x20000, 0x0, 0x40000, 0x0, 0x80000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x4040000, 0x0, 0x0, 0x10080000, 0x0, 0x0,
+        0x0, 0x0, 0x404040000, 0x0, 0x0, 0x0, 0x2010080000, 0x0,
+        0x0, 0x0, 0x40404040000, 0x0, 0x0, 0x0, 0x0, 0x402010080000,
+        0x0, 0x0, 0x4040404040000, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x600, 0x400, 0x0, 0x0, 0x0, 0x1000, 0x3000, 0x7000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x40000, 0x0, 0x80000, 0x0, 0x100000, 0x0, 0x0,
+        0x2040000, 0x0, 0x0, 0x8080000, 0x0, 0x0, 0x20100000, 0x0,
+        0x0, 0x0, 0x0, 0x808080000, 0x0, 0x0, 0x0, 0x4020100000,
+        0x0, 0x0, 0x0, 0x80808080000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x8080808080000, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xe00, 0xc00, 0x800, 0x0, 0x0, 0x0, 0x2000, 0x6000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x80000, 0x0, 0x100000, 0x0, 0x200000, 0x0,
+        0x0, 0x4080000, 0x0, 0x0, 0x10100000, 0x0, 0x0, 0x40200000,
+        0x204080000, 0x0, 0x0, 0x0, 0x1010100000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x101010100000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x10101010100000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1e00, 0x1c00, 0x1800, 0x1000, 0x0, 0x0, 0x0, 0x4000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x100000, 0x0, 0x200000, 0x0, 0x400000,
+        0x0, 0x0, 0x8100000, 0x0, 0x0, 0x20200000, 0x0, 0x0,
+        0x0, 0x408100000, 0x0, 0x0, 0x0, 0x2020200000, 0x0, 0x0,
+        0x20408100000, 0x0, 0x0, 0x0, 0x0, 0x202020200000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x20202020200000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x3e00, 0x3c00, 0x3800, 0x3000, 0x2000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x200000, 0x0, 0x400000, 0x0,
+        0x0, 0x0, 0x0, 0x10200000, 0x0, 0x0, 0x40400000, 0x0,
+        0x0, 0x0, 0x810200000, 0x0, 0x0, 0x0, 0x4040400000, 0x0,
+        0x0, 0x40810200000, 0x0, 0x0, 0x0, 0x0, 0x404040400000, 0x0,
+        0x2040810200000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40404040400000, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x7e00, 0x7c00, 0x7800, 0x7000, 0x6000, 0x4000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x400000, 0x0, 0x800000,
+        0x0, 0x0, 0x0, 0x0, 0x20400000, 0x0, 0x0, 0x80800000,
+        0x0, 0x0, 0x0, 0x1020400000, 0x0, 0x0, 0x0, 0x8080800000,
+        0x0, 0x0, 0x81020400000, 0x0, 0x0, 0x0, 0x0, 0x808080800000,
+        0x0, 0x4081020400000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80808080800000,
+    ],
+    [
+        0x100, 0x0, 0x200, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x20000, 0x60000, 0xe0000, 0x1e0000, 0x3e0000, 0x7e0000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1000000, 0x0, 0x2000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101000000, 0x0, 0x0, 0x402000000, 0x0, 0x0, 0x0, 0x0,
+        0x10101000000, 0x0, 0x0, 0x0, 0x80402000000, 0x0, 0x0, 0x0,
+        0x1010101000000, 0x0, 0x0, 0x0, 0x0, 0x10080402000000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x200, 0x0, 0x400, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x40000, 0xc0000, 0x1c0000, 0x3c0000, 0x7c0000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x2000000, 0x0, 0x4000000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202000000, 0x0, 0x0, 0x804000000, 0x0, 0x0, 0x0,
+        0x0, 0x20202000000, 0x0, 0x0, 0x0, 0x100804000000, 0x0, 0x0,
+        0x0, 0x2020202000000, 0x0, 0x0, 0x0, 0x0, 0x20100804000000, 0x0,
+    ],
+    [
+        0x200, 0x0, 0x400, 0x0, 0x800, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x20000, 0x0, 0x0, 0x0, 0x80000, 0x180000, 0x380000, 0x780000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x2000000, 0x0, 0x4000000, 0x0, 0x8000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404000000, 0x0, 0x0, 0x1008000000, 0x0, 0x0,
+        0x0, 0x0, 0x40404000000, 0x0, 0x0, 0x0, 0x201008000000, 0x0,
+        0x0, 0x0, 0x4040404000000, 0x0, 0x0, 0x0, 0x0, 0x40201008000000,
+    ],
+    [
+        0x0, 0x400, 0x0, 0x800, 0x0, 0x1000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x60000, 0x40000, 0x0, 0x0, 0x0, 0x100000, 0x300000, 0x700000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x4000000, 0x0, 0x8000000, 0x0, 0x10000000, 0x0, 0x0,
+        0x204000000, 0x0, 0x0, 0x808000000, 0x0, 0x0, 0x2010000000, 0x0,
+        0x0, 0x0, 0x0, 0x80808000000, 0x0, 0x0, 0x0, 0x402010000000,
+        0x0, 0x0, 0x0, 0x8080808000000, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x800, 0x0, 0x1000, 0x0, 0x2000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xe0000, 0xc0000, 0x80000, 0x0, 0x0, 0x0, 0x200000, 0x600000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x8000000, 0x0, 0x10000000, 0x0, 0x20000000, 0x0,
+        0x0, 0x408000000, 0x0, 0x0, 0x1010000000, 0x0, 0x0, 0x4020000000,
+        0x20408000000, 0x0, 0x0, 0x0, 0x101010000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x10101010000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x1000, 0x0, 0x2000, 0x0, 0x4000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1e0000, 0x1c0000, 0x180000, 0x100000, 0x0, 0x0, 0x0, 0x400000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x10000000, 0x0, 0x20000000, 0x0, 0x40000000,
+        0x0, 0x0, 0x810000000, 0x0, 0x0, 0x2020000000, 0x0, 0x0,
+        0x0, 0x40810000000, 0x0, 0x0, 0x0, 0x202020000000, 0x0, 0x0,
+        0x2040810000000, 0x0, 0x0, 0x0, 0x0, 0x20202020000000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x2000, 0x0, 0x4000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x3e0000, 0x3c0000, 0x380000, 0x300000, 0x200000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x20000000, 0x0, 0x40000000, 0x0,
+        0x0, 0x0, 0x0, 0x1020000000, 0x0, 0x0, 0x4040000000, 0x0,
+        0x0, 0x0, 0x81020000000, 0x0, 0x0, 0x0, 0x404040000000, 0x0,
+        0x0, 0x4081020000000, 0x0, 0x0, 0x0, 0x0, 0x40404040000000, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x4000, 0x0, 0x8000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x7e0000, 0x7c0000, 0x780000, 0x700000, 0x600000, 0x400000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x40000000, 0x0, 0x80000000,
+        0x0, 0x0, 0x0, 0x0, 0x2040000000, 0x0, 0x0, 0x8080000000,
+        0x0, 0x0, 0x0, 0x102040000000, 0x0, 0x0, 0x0, 0x808080000000,
+        0x0, 0x0, 0x8102040000000, 0x0, 0x0, 0x0, 0x0, 0x80808080000000,
+    ],
+    [
+        0x10100, 0x0, 0x0, 0x20400, 0x0, 0x0, 0x0, 0x0,
+        0x10000, 0x0, 0x20000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x2000000, 0x6000000, 0xe000000, 0x1e000000, 0x3e000000, 0x7e000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x100000000, 0x0, 0x200000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x10100000000, 0x0, 0x0, 0x40200000000, 0x0, 0x0, 0x0, 0x0,
+        0x1010100000000, 0x0, 0x0, 0x0, 0x8040200000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x20200, 0x0, 0x0, 0x40800, 0x0, 0x0, 0x0,
+        0x0, 0x20000, 0x0, 0x40000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x4000000, 0xc000000, 0x1c000000, 0x3c000000, 0x7c000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x200000000, 0x0, 0x400000000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x20200000000, 0x0, 0x0, 0x80400000000, 0x0, 0x0, 0x0,
+        0x0, 0x2020200000000, 0x0, 0x0, 0x0, 0x10080400000000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x40400, 0x0, 0x0, 0x81000, 0x0, 0x0,
+        0x20000, 0x0, 0x40000, 0x0, 0x80000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x2000000, 0x0, 0x0, 0x0, 0x8000000, 0x18000000, 0x38000000, 0x78000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x200000000, 0x0, 0x400000000, 0x0, 0x800000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x40400000000, 0x0, 0x0, 0x100800000000, 0x0, 0x0,
+        0x0, 0x0, 0x4040400000000, 0x0, 0x0, 0x0, 0x20100800000000, 0x0,
+    ],
+    [
+        0x40200, 0x0, 0x0, 0x80800, 0x0, 0x0, 0x102000, 0x0,
+        0x0, 0x40000, 0x0, 0x80000, 0x0, 0x100000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x6000000, 0x4000000, 0x0, 0x0, 0x0, 0x10000000, 0x30000000, 0x70000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x400000000, 0x0, 0x800000000, 0x0, 0x1000000000, 0x0, 0x0,
+        0x20400000000, 0x0, 0x0, 0x80800000000, 0x0, 0x0, 0x201000000000, 0x0,
+        0x0, 0x0, 0x0, 0x8080800000000, 0x0, 0x0, 0x0, 0x40201000000000,
+    ],
+    [
+        0x0, 0x80400, 0x0, 0x0, 0x101000, 0x0, 0x0, 0x204000,
+        0x0, 0x0, 0x80000, 0x0, 0x100000, 0x0, 0x200000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xe000000, 0xc000000, 0x8000000, 0x0, 0x0, 0x0, 0x20000000, 0x60000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x800000000, 0x0, 0x1000000000, 0x0, 0x2000000000, 0x0,
+        0x0, 0x40800000000, 0x0, 0x0, 0x101000000000, 0x0, 0x0, 0x402000000000,
+        0x2040800000000, 0x0, 0x0, 0x0, 0x10101000000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x100800, 0x0, 0x0, 0x202000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x100000, 0x0, 0x200000, 0x0, 0x400000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1e000000, 0x1c000000, 0x18000000, 0x10000000, 0x0, 0x0, 0x0, 0x40000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x1000000000, 0x0, 0x2000000000, 0x0, 0x4000000000,
+        0x0, 0x0, 0x81000000000, 0x0, 0x0, 0x202000000000, 0x0, 0x0,
+        0x0, 0x4081000000000, 0x0, 0x0, 0x0, 0x20202000000000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x201000, 0x0, 0x0, 0x404000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x200000, 0x0, 0x400000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x3e000000, 0x3c000000, 0x38000000, 0x30000000, 0x20000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x2000000000, 0x0, 0x4000000000, 0x0,
+        0x0, 0x0, 0x0, 0x102000000000, 0x0, 0x0, 0x404000000000, 0x0,
+        0x0, 0x0, 0x8102000000000, 0x0, 0x0, 0x0, 0x40404000000000, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x402000, 0x0, 0x0, 0x808000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x400000, 0x0, 0x800000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x7e000000, 0x7c000000, 0x78000000, 0x70000000, 0x60000000, 0x40000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x4000000000, 0x0, 0x8000000000,
+        0x0, 0x0, 0x0, 0x0, 0x204000000000, 0x0, 0x0, 0x808000000000,
+        0x0, 0x0, 0x0, 0x10204000000000, 0x0, 0x0, 0x0, 0x80808000000000,
+    ],
+    [
+        0x1010100, 0x0, 0x0, 0x0, 0x2040800, 0x0, 0x0, 0x0,
+        0x1010000, 0x0, 0x0, 0x2040000, 0x0, 0x0, 0x0, 0x0,
+        0x1000000, 0x0, 0x2000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x200000000, 0x600000000, 0xe00000000, 0x1e00000000, 0x3e00000000, 0x7e00000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x10000000000, 0x0, 0x20000000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1010000000000, 0x0, 0x0, 0x4020000000000, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x2020200, 0x0, 0x0, 0x0, 0x4081000, 0x0, 0x0,
+        0x0, 0x2020000, 0x0, 0x0, 0x4080000, 0x0, 0x0, 0x0,
+        0x0, 0x2000000, 0x0, 0x4000000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x400000000, 0xc00000000, 0x1c00000000, 0x3c00000000, 0x7c00000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x20000000000, 0x0, 0x40000000000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x2020000000000, 0x0, 0x0, 0x8040000000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x4040400, 0x0, 0x0, 0x0, 0x8102000, 0x0,
+        0x0, 0x0, 0x4040000, 0x0, 0x0, 0x8100000, 0x0, 0x0,
+        0x2000000, 0x0, 0x4000000, 0x0, 0x8000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x200000000, 0x0, 0x0, 0x0, 0x800000000, 0x1800000000, 0x3800000000, 0x7800000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x20000000000, 0x0, 0x40000000000, 0x0, 0x80000000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x4040000000000, 0x0, 0x0, 0x10080000000000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x8080800, 0x0, 0x0, 0x0, 0x10204000,
+        0x4020000, 0x0, 0x0, 0x8080000, 0x0, 0x0, 0x10200000, 0x0,
+        0x0, 0x4000000, 0x0, 0x8000000, 0x0, 0x10000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x600000000, 0x400000000, 0x0, 0x0, 0x0, 0x1000000000, 0x3000000000, 0x7000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x40000000000, 0x0, 0x80000000000, 0x0, 0x100000000000, 0x0, 0x0,
+        0x2040000000000, 0x0, 0x0, 0x8080000000000, 0x0, 0x0, 0x20100000000000, 0x0,
+    ],
+    [
+        0x8040200, 0x0, 0x0, 0x0, 0x10101000, 0x0, 0x0, 0x0,
+        0x0, 0x8040000, 0x0, 0x0, 0x10100000, 0x0, 0x0, 0x20400000,
+        0x0, 0x0, 0x8000000, 0x0, 0x10000000, 0x0, 0x20000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xe00000000, 0xc00000000, 0x800000000, 0x0, 0x0, 0x0, 0x2000000000, 0x6000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x80000000000, 0x0, 0x100000000000, 0x0, 0x200000000000, 0x0,
+        0x0, 0x4080000000000, 0x0, 0x0, 0x10100000000000, 0x0, 0x0, 0x40200000000000,
+    ],
+    [
+        0x0, 0x10080400, 0x0, 0x0, 0x0, 0x20202000, 0x0, 0x0,
+        0x0, 0x0, 0x10080000, 0x0, 0x0, 0x20200000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x10000000, 0x0, 0x20000000, 0x0, 0x40000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1e00000000, 0x1c00000000, 0x1800000000, 0x1000000000, 0x0, 0x0, 0x0, 0x4000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x100000000000, 0x0, 0x200000000000, 0x0, 0x400000000000,
+        0x0, 0x0, 0x8100000000000, 0x0, 0x0, 0x20200000000000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x20100800, 0x0, 0x0, 0x0, 0x40404000, 0x0,
+        0x0, 0x0, 0x0, 0x20100000, 0x0, 0x0, 0x40400000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x20000000, 0x0, 0x40000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x3e00000000, 0x3c00000000, 0x3800000000, 0x3000000000, 0x2000000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x200000000000, 0x0, 0x400000000000, 0x0,
+        0x0, 0x0, 0x0, 0x10200000000000, 0x0, 0x0, 0x40400000000000, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x40201000, 0x0, 0x0, 0x0, 0x80808000,
+        0x0, 0x0, 0x0, 0x0, 0x40200000, 0x0, 0x0, 0x80800000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x40000000, 0x0, 0x80000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x7e00000000, 0x7c00000000, 0x7800000000, 0x7000000000, 0x6000000000, 0x4000000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x400000000000, 0x0, 0x800000000000,
+        0x0, 0x0, 0x0, 0x0, 0x20400000000000, 0x0, 0x0, 0x80800000000000,
+    ],
+    [
+        0x101010100, 0x0, 0x0, 0x0, 0x0, 0x204081000, 0x0, 0x0,
+        0x101010000, 0x0, 0x0, 0x0, 0x204080000, 0x0, 0x0, 0x0,
+        0x101000000, 0x0, 0x0, 0x204000000, 0x0, 0x0, 0x0, 0x0,
+        0x100000000, 0x0, 0x200000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x20000000000, 0x60000000000, 0xe0000000000, 0x1e0000000000, 0x3e0000000000, 0x7e0000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1000000000000, 0x0, 0x2000000000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x202020200, 0x0, 0x0, 0x0, 0x0, 0x408102000, 0x0,
+        0x0, 0x202020000, 0x0, 0x0, 0x0, 0x408100000, 0x0, 0x0,
+        0x0, 0x202000000, 0x0, 0x0, 0x408000000, 0x0, 0x0, 0x0,
+        0x0, 0x200000000, 0x0, 0x400000000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x40000000000, 0xc0000000000, 0x1c0000000000, 0x3c0000000000, 0x7c0000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x2000000000000, 0x0, 0x4000000000000, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x404040400, 0x0, 0x0, 0x0, 0x0, 0x810204000,
+        0x0, 0x0, 0x404040000, 0x0, 0x0, 0x0, 0x810200000, 0x0,
+        0x0, 0x0, 0x404000000, 0x0, 0x0, 0x810000000, 0x0, 0x0,
+        0x200000000, 0x0, 0x400000000, 0x0, 0x800000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x20000000000, 0x0, 0x0, 0x0, 0x80000000000, 0x180000000000, 0x380000000000, 0x780000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x2000000000000, 0x0, 0x4000000000000, 0x0, 0x8000000000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x808080800, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080000, 0x0, 0x0, 0x0, 0x1020400000,
+        0x402000000, 0x0, 0x0, 0x808000000, 0x0, 0x0, 0x1020000000, 0x0,
+        0x0, 0x400000000, 0x0, 0x800000000, 0x0, 0x1000000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x60000000000, 0x40000000000, 0x0, 0x0, 0x0, 0x100000000000, 0x300000000000, 0x700000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x4000000000000, 0x0, 0x8000000000000, 0x0, 0x10000000000000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x1010101000, 0x0, 0x0, 0x0,
+        0x804020000, 0x0, 0x0, 0x0, 0x1010100000, 0x0, 0x0, 0x0,
+        0x0, 0x804000000, 0x0, 0x0, 0x1010000000, 0x0, 0x0, 0x2040000000,
+        0x0, 0x0, 0x800000000, 0x0, 0x1000000000, 0x0, 0x2000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xe0000000000, 0xc0000000000, 0x80000000000, 0x0, 0x0, 0x0, 0x200000000000, 0x600000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x8000000000000, 0x0, 0x10000000000000, 0x0, 0x20000000000000, 0x0,
+    ],
+    [
+        0x1008040200, 0x0, 0x0, 0x0, 0x0, 0x2020202000, 0x0, 0x0,
+        0x0, 0x1008040000, 0x0, 0x0, 0x0, 0x2020200000, 0x0, 0x0,
+        0x0, 0x0, 0x1008000000, 0x0, 0x0, 0x2020000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x1000000000, 0x0, 0x2000000000, 0x0, 0x4000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1e0000000000, 0x1c0000000000, 0x180000000000, 0x100000000000, 0x0, 0x0, 0x0, 0x400000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x10000000000000, 0x0, 0x20000000000000, 0x0, 0x40000000000000,
+    ],
+    [
+        0x0, 0x2010080400, 0x0, 0x0, 0x0, 0x0, 0x4040404000, 0x0,
+        0x0, 0x0, 0x2010080000, 0x0, 0x0, 0x0, 0x4040400000, 0x0,
+        0x0, 0x0, 0x0, 0x2010000000, 0x0, 0x0, 0x4040000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x2000000000, 0x0, 0x4000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x3e0000000000, 0x3c0000000000, 0x380000000000, 0x300000000000, 0x200000000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x20000000000000, 0x0, 0x40000000000000, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x4020100800, 0x0, 0x0, 0x0, 0x0, 0x8080808000,
+        0x0, 0x0, 0x0, 0x4020100000, 0x0, 0x0, 0x0, 0x8080800000,
+        0x0, 0x0, 0x0, 0x0, 0x4020000000, 0x0, 0x0, 0x8080000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x4000000000, 0x0, 0x8000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x7e0000000000, 0x7c0000000000, 0x780000000000, 0x700000000000, 0x600000000000, 0x400000000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x40000000000000, 0x0, 0x80000000000000,
+    ],
+    [
+        0x10101010100, 0x0, 0x0, 0x0, 0x0, 0x0, 0x20408102000, 0x0,
+        0x10101010000, 0x0, 0x0, 0x0, 0x0, 0x20408100000, 0x0, 0x0,
+        0x10101000000, 0x0, 0x0, 0x0, 0x20408000000, 0x0, 0x0, 0x0,
+        0x10100000000, 0x0, 0x0, 0x20400000000, 0x0, 0x0, 0x0, 0x0,
+        0x10000000000, 0x0, 0x20000000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x2000000000000, 0x6000000000000, 0xe000000000000, 0x1e000000000000, 0x3e000000000000, 0x7e000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x20202020200, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40810204000,
+        0x0, 0x20202020000, 0x0, 0x0, 0x0, 0x0, 0x40810200000, 0x0,
+        0x0, 0x20202000000, 0x0, 0x0, 0x0, 0x40810000000, 0x0, 0x0,
+        0x0, 0x20200000000, 0x0, 0x0, 0x40800000000, 0x0, 0x0, 0x0,
+        0x0, 0x20000000000, 0x0, 0x40000000000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x4000000000000, 0xc000000000000, 0x1c000000000000, 0x3c000000000000, 0x7c000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x40404040400, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x40404040000, 0x0, 0x0, 0x0, 0x0, 0x81020400000,
+        0x0, 0x0, 0x40404000000, 0x0, 0x0, 0x0, 0x81020000000, 0x0,
+        0x0, 0x0, 0x40400000000, 0x0, 0x0, 0x81000000000, 0x0, 0x0,
+        0x20000000000, 0x0, 0x40000000000, 0x0, 0x80000000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x2000000000000, 0x0, 0x0, 0x0, 0x8000000000000, 0x18000000000000, 0x38000000000000, 0x78000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x80808080800, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x80808080000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x80808000000, 0x0, 0x0, 0x0, 0x102040000000,
+        0x40200000000, 0x0, 0x0, 0x80800000000, 0x0, 0x0, 0x102000000000, 0x0,
+        0x0, 0x40000000000, 0x0, 0x80000000000, 0x0, 0x100000000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x6000000000000, 0x4000000000000, 0x0, 0x0, 0x0, 0x10000000000000, 0x30000000000000, 0x70000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x101010101000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x101010100000, 0x0, 0x0, 0x0,
+        0x80402000000, 0x0, 0x0, 0x0, 0x101010000000, 0x0, 0x0, 0x0,
+        0x0, 0x80400000000, 0x0, 0x0, 0x101000000000, 0x0, 0x0, 0x204000000000,
+        0x0, 0x0, 0x80000000000, 0x0, 0x100000000000, 0x0, 0x200000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xe000000000000, 0xc000000000000, 0x8000000000000, 0x0, 0x0, 0x0, 0x20000000000000, 0x60000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x202020202000, 0x0, 0x0,
+        0x100804020000, 0x0, 0x0, 0x0, 0x0, 0x202020200000, 0x0, 0x0,
+        0x0, 0x100804000000, 0x0, 0x0, 0x0, 0x202020000000, 0x0, 0x0,
+        0x0, 0x0, 0x100800000000, 0x0, 0x0, 0x202000000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x100000000000, 0x0, 0x200000000000, 0x0, 0x400000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1e000000000000, 0x1c000000000000, 0x18000000000000, 0x10000000000000, 0x0, 0x0, 0x0, 0x40000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x201008040200, 0x0, 0x0, 0x0, 0x0, 0x0, 0x404040404000, 0x0,
+        0x0, 0x201008040000, 0x0, 0x0, 0x0, 0x0, 0x404040400000, 0x0,
+        0x0, 0x0, 0x201008000000, 0x0, 0x0, 0x0, 0x404040000000, 0x0,
+        0x0, 0x0, 0x0, 0x201000000000, 0x0, 0x0, 0x404000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x200000000000, 0x0, 0x400000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x3e000000000000, 0x3c000000000000, 0x38000000000000, 0x30000000000000, 0x20000000000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x402010080400, 0x0, 0x0, 0x0, 0x0, 0x0, 0x808080808000,
+        0x0, 0x0, 0x402010080000, 0x0, 0x0, 0x0, 0x0, 0x808080800000,
+        0x0, 0x0, 0x0, 0x402010000000, 0x0, 0x0, 0x0, 0x808080000000,
+        0x0, 0x0, 0x0, 0x0, 0x402000000000, 0x0, 0x0, 0x808000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x400000000000, 0x0, 0x800000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x7e000000000000, 0x7c000000000000, 0x78000000000000, 0x70000000000000, 0x60000000000000, 0x40000000000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x1010101010100, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2040810204000,
+        0x1010101010000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2040810200000, 0x0,
+        0x1010101000000, 0x0, 0x0, 0x0, 0x0, 0x2040810000000, 0x0, 0x0,
+        0x1010100000000, 0x0, 0x0, 0x0, 0x2040800000000, 0x0, 0x0, 0x0,
+        0x1010000000000, 0x0, 0x0, 0x2040000000000, 0x0, 0x0, 0x0, 0x0,
+        0x1000000000000, 0x0, 0x2000000000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x200000000000000, 0x600000000000000, 0xe00000000000000, 0x1e00000000000000, 0x3e00000000000000, 0x7e00000000000000,
+    ],
+    [
+        0x0, 0x2020202020200, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x2020202020000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4081020400000,
+        0x0, 0x2020202000000, 0x0, 0x0, 0x0, 0x0, 0x4081020000000, 0x0,
+        0x0, 0x2020200000000, 0x0, 0x0, 0x0, 0x4081000000000, 0x0, 0x0,
+        0x0, 0x2020000000000, 0x0, 0x0, 0x4080000000000, 0x0, 0x0, 0x0,
+        0x0, 0x2000000000000, 0x0, 0x4000000000000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x400000000000000, 0xc00000000000000, 0x1c00000000000000, 0x3c00000000000000, 0x7c00000000000000,
+    ],
+    [
+        0x0, 0x0, 0x4040404040400, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x4040404040000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x4040404000000, 0x0, 0x0, 0x0, 0x0, 0x8102040000000,
+        0x0, 0x0, 0x4040400000000, 0x0, 0x0, 0x0, 0x8102000000000, 0x0,
+        0x0, 0x0, 0x4040000000000, 0x0, 0x0, 0x8100000000000, 0x0, 0x0,
+        0x2000000000000, 0x0, 0x4000000000000, 0x0, 0x8000000000000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x200000000000000, 0x0, 0x0, 0x0, 0x800000000000000, 0x1800000000000000, 0x3800000000000000, 0x7800000000000000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x8080808080800, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x8080808080000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x8080808000000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x8080800000000, 0x0, 0x0, 0x0, 0x10204000000000,
+        0x4020000000000, 0x0, 0x0, 0x8080000000000, 0x0, 0x0, 0x10200000000000, 0x0,
+        0x0, 0x4000000000000, 0x0, 0x8000000000000, 0x0, 0x10000000000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x600000000000000, 0x400000000000000, 0x0, 0x0, 0x0, 0x1000000000000000, 0x3000000000000000, 0x7000000000000000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x10101010101000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x10101010100000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x10101010000000, 0x0, 0x0, 0x0,
+        0x8040200000000, 0x0, 0x0, 0x0, 0x10101000000000, 0x0, 0x0, 0x0,
+        0x0, 0x8040000000000, 0x0, 0x0, 0x10100000000000, 0x0, 0x0, 0x20400000000000,
+        0x0, 0x0, 0x8000000000000, 0x0, 0x10000000000000, 0x0, 0x20000000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xe00000000000000, 0xc00000000000000, 0x800000000000000, 0x0, 0x0, 0x0, 0x2000000000000000, 0x6000000000000000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x20202020202000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x20202020200000, 0x0, 0x0,
+        0x10080402000000, 0x0, 0x0, 0x0, 0x0, 0x20202020000000, 0x0, 0x0,
+        0x0, 0x10080400000000, 0x0, 0x0, 0x0, 0x20202000000000, 0x0, 0x0,
+        0x0, 0x0, 0x10080000000000, 0x0, 0x0, 0x20200000000000, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x10000000000000, 0x0, 0x20000000000000, 0x0, 0x40000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x1e00000000000000, 0x1c00000000000000, 0x1800000000000000, 0x1000000000000000, 0x0, 0x0, 0x0, 0x4000000000000000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40404040404000, 0x0,
+        0x20100804020000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x40404040400000, 0x0,
+        0x0, 0x20100804000000, 0x0, 0x0, 0x0, 0x0, 0x40404040000000, 0x0,
+        0x0, 0x0, 0x20100800000000, 0x0, 0x0, 0x0, 0x40404000000000, 0x0,
+        0x0, 0x0, 0x0, 0x20100000000000, 0x0, 0x0, 0x40400000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x20000000000000, 0x0, 0x40000000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x3e00000000000000, 0x3c00000000000000, 0x3800000000000000, 0x3000000000000000, 0x2000000000000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x40201008040200, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80808080808000,
+        0x0, 0x40201008040000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80808080800000,
+        0x0, 0x0, 0x40201008000000, 0x0, 0x0, 0x0, 0x0, 0x80808080000000,
+        0x0, 0x0, 0x0, 0x40201000000000, 0x0, 0x0, 0x0, 0x80808000000000,
+        0x0, 0x0, 0x0, 0x0, 0x40200000000000, 0x0, 0x0, 0x80800000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x40000000000000, 0x0, 0x80000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x7e00000000000000, 0x7c00000000000000, 0x7800000000000000, 0x7000000000000000, 0x6000000000000000, 0x4000000000000000, 0x0, 0x0,
+    ],
+];
+
+pub static LINE: [[u64; 64]; 64] = [
+    [
+        0x0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x101010101010101, 0x8040201008040201, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x8040201008040201, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x8040201008040201, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x8040201008040201, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x8040201008040201, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8040201008040201, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8040201008040201,
+    ],
+    [
+        0xff, 0x0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x102, 0x202020202020202, 0x80402010080402, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x80402010080402, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x80402010080402, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x80402010080402, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x80402010080402, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80402010080402,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0xff, 0xff, 0x0, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x0, 0x10204, 0x404040404040404, 0x804020100804, 0x0, 0x0, 0x0, 0x0,
+        0x10204, 0x0, 0x404040404040404, 0x0, 0x804020100804, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x804020100804, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x804020100804, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x804020100804,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0xff, 0xff, 0xff, 0x0, 0xff, 0xff, 0xff, 0xff,
+        0x0, 0x0, 0x1020408, 0x808080808080808, 0x8040201008, 0x0, 0x0, 0x0,
+        0x0, 0x1020408, 0x0, 0x808080808080808, 0x0, 0x8040201008, 0x0, 0x0,
+        0x1020408, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x8040201008, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x8040201008,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0xff, 0xff, 0xff, 0xff, 0x0, 0xff, 0xff, 0xff,
+        0x0, 0x0, 0x0, 0x102040810, 0x1010101010101010, 0x80402010, 0x0, 0x0,
+        0x0, 0x0, 0x102040810, 0x0, 0x1010101010101010, 0x0, 0x80402010, 0x0,
+        0x0, 0x102040810, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x80402010,
+        0x102040810, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+    ],
+    [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0x0, 0xff, 0xff,
+        0x0, 0x0, 0x0, 0x0, 0x10204081020, 0x2020202020202020, 0x804020, 0x0,
+        0x0, 0x0, 0x0, 0x10204081020, 0x0, 0x2020202020202020, 0x0, 0x804020,
+        0x0, 0x0, 0x10204081020, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x10204081020, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x10204081020, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+    ],
+    [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x0, 0xff,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x1020408102040, 0x4040404040404040, 0x8040,
+        0x0, 0x0, 0x0, 0x0, 0x1020408102040, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x1020408102040, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x1020408102040, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x1020408102040, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x1020408102040, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+    ],
+    [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x102040810204080, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x102040810204080, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x102040810204080, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x102040810204080, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x102040810204080, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x102040810204080, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x102040810204080, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+    ],
+    [
+        0x101010101010101, 0x102, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0xff00, 0xff00, 0xff00, 0xff00, 0xff00, 0xff00, 0xff00,
+        0x101010101010101, 0x4020100804020100, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x4020100804020100, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x4020100804020100, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x4020100804020100, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x4020100804020100, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4020100804020100, 0x0,
+    ],
+    [
+        0x8040201008040201, 0x202020202020202, 0x10204, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xff00, 0x0, 0xff00, 0xff00, 0xff00, 0xff00, 0xff00, 0xff00,
+        0x10204, 0x202020202020202, 0x8040201008040201, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x8040201008040201, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x8040201008040201, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x8040201008040201, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x8040201008040201, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8040201008040201,
+    ],
+    [
+        0x0, 0x80402010080402, 0x404040404040404, 0x1020408, 0x0, 0x0, 0x0, 0x0,
+        0xff00, 0xff00, 0x0, 0xff00, 0xff00, 0xff00, 0xff00, 0xff00,
+        0x0, 0x1020408, 0x404040404040404, 0x80402010080402, 0x0, 0x0, 0x0, 0x0,
+        0x1020408, 0x0, 0x404040404040404, 0x0, 0x80402010080402, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x80402010080402, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x80402010080402, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x80402010080402,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x804020100804, 0x808080808080808, 0x102040810, 0x0, 0x0, 0x0,
+        0xff00, 0xff00, 0xff00, 0x0, 0xff00, 0xff00, 0xff00, 0xff00,
+        0x0, 0x0, 0x102040810, 0x808080808080808, 0x804020100804, 0x0, 0x0, 0x0,
+        0x0, 0x102040810, 0x0, 0x808080808080808, 0x0, 0x804020100804, 0x0, 0x0,
+        0x102040810, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x804020100804, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x804020100804,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x8040201008, 0x1010101010101010, 0x10204081020, 0x0, 0x0,
+        0xff00, 0xff00, 0xff00, 0xff00, 0x0, 0xff00, 0xff00, 0xff00,
+        0x0, 0x0, 0x0, 0x10204081020, 0x1010101010101010, 0x8040201008, 0x0, 0x0,
+        0x0, 0x0, 0x10204081020, 0x0, 0x1010101010101010, 0x0, 0x8040201008, 0x0,
+        0x0, 0x10204081020, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x8040201008,
+        0x10204081020, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x80402010, 0x2020202020202020, 0x1020408102040, 0x0,
+        0xff00, 0xff00, 0xff00, 0xff00, 0xff00, 0x0, 0xff00, 0xff00,
+        0x0, 0x0, 0x0, 0x0, 0x1020408102040, 0x2020202020202020, 0x80402010, 0x0,
+        0x0, 0x0, 0x0, 0x1020408102040, 0x0, 0x2020202020202020, 0x0, 0x80402010,
+        0x0, 0x0, 0x1020408102040, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x1020408102040, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x1020408102040, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x804020, 0x4040404040404040, 0x102040810204080,
+        0xff00, 0xff00, 0xff00, 0xff00, 0xff00, 0xff00, 0x0, 0xff00,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x102040810204080, 0x4040404040404040, 0x804020,
+        0x0, 0x0, 0x0, 0x0, 0x102040810204080, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x102040810204080, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x102040810204080, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x102040810204080, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x102040810204080, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8040, 0x8080808080808080,
+        0xff00, 0xff00, 0xff00, 0xff00, 0xff00, 0xff00, 0xff00, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x204081020408000, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x204081020408000, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x204081020408000, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x204081020408000, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x204081020408000, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x204081020408000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+    ],
+    [
+        0x101010101010101, 0x0, 0x10204, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x10204, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000,
+        0x101010101010101, 0x2010080402010000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x2010080402010000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x2010080402010000, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x2010080402010000, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x2010080402010000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x202020202020202, 0x0, 0x1020408, 0x0, 0x0, 0x0, 0x0,
+        0x4020100804020100, 0x202020202020202, 0x1020408, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xff0000, 0x0, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000,
+        0x1020408, 0x202020202020202, 0x4020100804020100, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x4020100804020100, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x4020100804020100, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x4020100804020100, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x4020100804020100, 0x0,
+    ],
+    [
+        0x8040201008040201, 0x0, 0x404040404040404, 0x0, 0x102040810, 0x0, 0x0, 0x0,
+        0x0, 0x8040201008040201, 0x404040404040404, 0x102040810, 0x0, 0x0, 0x0, 0x0,
+        0xff0000, 0xff0000, 0x0, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000,
+        0x0, 0x102040810, 0x404040404040404, 0x8040201008040201, 0x0, 0x0, 0x0, 0x0,
+        0x102040810, 0x0, 0x404040404040404, 0x0, 0x8040201008040201, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x8040201008040201, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x8040201008040201, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x8040201008040201,
+    ],
+    [
+        0x0, 0x80402010080402, 0x0, 0x808080808080808, 0x0, 0x10204081020, 0x0, 0x0,
+        0x0, 0x0, 0x80402010080402, 0x808080808080808, 0x10204081020, 0x0, 0x0, 0x0,
+        0xff0000, 0xff0000, 0xff0000, 0x0, 0xff0000, 0xff0000, 0xff0000, 0xff0000,
+        0x0, 0x0, 0x10204081020, 0x808080808080808, 0x80402010080402, 0x0, 0x0, 0x0,
+        0x0, 0x10204081020, 0x0, 0x808080808080808, 0x0, 0x80402010080402, 0x0, 0x0,
+        0x10204081020, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x80402010080402, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x80402010080402,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x804020100804, 0x0, 0x1010101010101010, 0x0, 0x1020408102040, 0x0,
+        0x0, 0x0, 0x0, 0x804020100804, 0x1010101010101010, 0x1020408102040, 0x0, 0x0,
+        0xff0000, 0xff0000, 0xff0000, 0xff0000, 0x0, 0xff0000, 0xff0000, 0xff0000,
+        0x0, 0x0, 0x0, 0x1020408102040, 0x1010101010101010, 0x804020100804, 0x0, 0x0,
+        0x0, 0x0, 0x1020408102040, 0x0, 0x1010101010101010, 0x0, 0x804020100804, 0x0,
+        0x0, 0x1020408102040, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x804020100804,
+        0x1020408102040, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x8040201008, 0x0, 0x2020202020202020, 0x0, 0x102040810204080,
+        0x0, 0x0, 0x0, 0x0, 0x8040201008, 0x2020202020202020, 0x102040810204080, 0x0,
+        0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0x0, 0xff0000, 0xff0000,
+        0x0, 0x0, 0x0, 0x0, 0x102040810204080, 0x2020202020202020, 0x8040201008, 0x0,
+        0x0, 0x0, 0x0, 0x102040810204080, 0x0, 0x2020202020202020, 0x0, 0x8040201008,
+        0x0, 0x0, 0x102040810204080, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x102040810204080, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x102040810204080, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x80402010, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x80402010, 0x4040404040404040, 0x204081020408000,
+        0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0x0, 0xff0000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x204081020408000, 0x4040404040404040, 0x80402010,
+        0x0, 0x0, 0x0, 0x0, 0x204081020408000, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x204081020408000, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x204081020408000, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x204081020408000, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x804020, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x804020, 0x8080808080808080,
+        0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0xff0000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x408102040800000, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x408102040800000, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x408102040800000, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x408102040800000, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x408102040800000, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+    ],
+    [
+        0x101010101010101, 0x0, 0x0, 0x1020408, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x1020408, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x1020408, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+        0x101010101010101, 0x1008040201000000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x1008040201000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x1008040201000000, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x1008040201000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x202020202020202, 0x0, 0x0, 0x102040810, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x102040810, 0x0, 0x0, 0x0, 0x0,
+        0x2010080402010000, 0x202020202020202, 0x102040810, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xff000000, 0x0, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+        0x102040810, 0x202020202020202, 0x2010080402010000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x2010080402010000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x2010080402010000, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x2010080402010000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x10204081020, 0x0, 0x0,
+        0x4020100804020100, 0x0, 0x404040404040404, 0x0, 0x10204081020, 0x0, 0x0, 0x0,
+        0x0, 0x4020100804020100, 0x404040404040404, 0x10204081020, 0x0, 0x0, 0x0, 0x0,
+        0xff000000, 0xff000000, 0x0, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+        0x0, 0x10204081020, 0x404040404040404, 0x4020100804020100, 0x0, 0x0, 0x0, 0x0,
+        0x10204081020, 0x0, 0x404040404040404, 0x0, 0x4020100804020100, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x4020100804020100, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x4020100804020100, 0x0,
+    ],
+    [
+        0x8040201008040201, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x1020408102040, 0x0,
+        0x0, 0x8040201008040201, 0x0, 0x808080808080808, 0x0, 0x1020408102040, 0x0, 0x0,
+        0x0, 0x0, 0x8040201008040201, 0x808080808080808, 0x1020408102040, 0x0, 0x0, 0x0,
+        0xff000000, 0xff000000, 0xff000000, 0x0, 0xff000000, 0xff000000, 0xff000000, 0xff000000,
+        0x0, 0x0, 0x1020408102040, 0x808080808080808, 0x8040201008040201, 0x0, 0x0, 0x0,
+        0x0, 0x1020408102040, 0x0, 0x808080808080808, 0x0, 0x8040201008040201, 0x0, 0x0,
+        0x1020408102040, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x8040201008040201, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x8040201008040201,
+    ],
+    [
+        0x0, 0x80402010080402, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x102040810204080,
+        0x0, 0x0, 0x80402010080402, 0x0, 0x1010101010101010, 0x0, 0x102040810204080, 0x0,
+        0x0, 0x0, 0x0, 0x80402010080402, 0x1010101010101010, 0x102040810204080, 0x0, 0x0,
+        0xff000000, 0xff000000, 0xff000000, 0xff000000, 0x0, 0xff000000, 0xff000000, 0xff000000,
+        0x0, 0x0, 0x0, 0x102040810204080, 0x1010101010101010, 0x80402010080402, 0x0, 0x0,
+        0x0, 0x0, 0x102040810204080, 0x0, 0x1010101010101010, 0x0, 0x80402010080402, 0x0,
+        0x0, 0x102040810204080, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x80402010080402,
+        0x102040810204080, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x804020100804, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x804020100804, 0x0, 0x2020202020202020, 0x0, 0x204081020408000,
+        0x0, 0x0, 0x0, 0x0, 0x804020100804, 0x2020202020202020, 0x204081020408000, 0x0,
+        0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0x0, 0xff000000, 0xff000000,
+        0x0, 0x0, 0x0, 0x0, 0x204081020408000, 0x2020202020202020, 0x804020100804, 0x0,
+        0x0, 0x0, 0x0, 0x204081020408000, 0x0, 0x2020202020202020, 0x0, 0x804020100804,
+        0x0, 0x0, 0x204081020408000, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x204081020408000, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x8040201008, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x8040201008, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x8040201008, 0x4040404040404040, 0x408102040800000,
+        0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0x0, 0xff000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x408102040800000, 0x4040404040404040, 0x8040201008,
+        0x0, 0x0, 0x0, 0x0, 0x408102040800000, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x408102040800000, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x408102040800000, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x80402010, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x80402010, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80402010, 0x8080808080808080,
+        0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0xff000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x810204080000000, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x810204080000000, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x810204080000000, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x810204080000000, 0x0, 0x0, 0x0, 0x8080808080808080,
+    ],
+    [
+        0x101010101010101, 0x0, 0x0, 0x0, 0x102040810, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x102040810, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x102040810, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x102040810, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000,
+        0x101010101010101, 0x804020100000000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x804020100000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x804020100000000, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x10204081020, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x10204081020, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x10204081020, 0x0, 0x0, 0x0, 0x0,
+        0x1008040201000000, 0x202020202020202, 0x10204081020, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xff00000000, 0x0, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000,
+        0x10204081020, 0x202020202020202, 0x1008040201000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x1008040201000000, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x1008040201000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x1020408102040, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x1020408102040, 0x0, 0x0,
+        0x2010080402010000, 0x0, 0x404040404040404, 0x0, 0x1020408102040, 0x0, 0x0, 0x0,
+        0x0, 0x2010080402010000, 0x404040404040404, 0x1020408102040, 0x0, 0x0, 0x0, 0x0,
+        0xff00000000, 0xff00000000, 0x0, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000,
+        0x0, 0x1020408102040, 0x404040404040404, 0x2010080402010000, 0x0, 0x0, 0x0, 0x0,
+        0x1020408102040, 0x0, 0x404040404040404, 0x0, 0x2010080402010000, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x2010080402010000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x102040810204080,
+        0x4020100804020100, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x102040810204080, 0x0,
+        0x0, 0x4020100804020100, 0x0, 0x808080808080808, 0x0, 0x102040810204080, 0x0, 0x0,
+        0x0, 0x0, 0x4020100804020100, 0x808080808080808, 0x102040810204080, 0x0, 0x0, 0x0,
+        0xff00000000, 0xff00000000, 0xff00000000, 0x0, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000,
+        0x0, 0x0, 0x102040810204080, 0x808080808080808, 0x4020100804020100, 0x0, 0x0, 0x0,
+        0x0, 0x102040810204080, 0x0, 0x808080808080808, 0x0, 0x4020100804020100, 0x0, 0x0,
+        0x102040810204080, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x4020100804020100, 0x0,
+    ],
+    [
+        0x8040201008040201, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x8040201008040201, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x204081020408000,
+        0x0, 0x0, 0x8040201008040201, 0x0, 0x1010101010101010, 0x0, 0x204081020408000, 0x0,
+        0x0, 0x0, 0x0, 0x8040201008040201, 0x1010101010101010, 0x204081020408000, 0x0, 0x0,
+        0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0x0, 0xff00000000, 0xff00000000, 0xff00000000,
+        0x0, 0x0, 0x0, 0x204081020408000, 0x1010101010101010, 0x8040201008040201, 0x0, 0x0,
+        0x0, 0x0, 0x204081020408000, 0x0, 0x1010101010101010, 0x0, 0x8040201008040201, 0x0,
+        0x0, 0x204081020408000, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x8040201008040201,
+    ],
+    [
+        0x0, 0x80402010080402, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x80402010080402, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x80402010080402, 0x0, 0x2020202020202020, 0x0, 0x408102040800000,
+        0x0, 0x0, 0x0, 0x0, 0x80402010080402, 0x2020202020202020, 0x408102040800000, 0x0,
+        0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0x0, 0xff00000000, 0xff00000000,
+        0x0, 0x0, 0x0, 0x0, 0x408102040800000, 0x2020202020202020, 0x80402010080402, 0x0,
+        0x0, 0x0, 0x0, 0x408102040800000, 0x0, 0x2020202020202020, 0x0, 0x80402010080402,
+        0x0, 0x0, 0x408102040800000, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x804020100804, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x804020100804, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x804020100804, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x804020100804, 0x4040404040404040, 0x810204080000000,
+        0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0x0, 0xff00000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x810204080000000, 0x4040404040404040, 0x804020100804,
+        0x0, 0x0, 0x0, 0x0, 0x810204080000000, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x810204080000000, 0x0, 0x0, 0x4040404040404040, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x8040201008, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x8040201008, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x8040201008, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8040201008, 0x8080808080808080,
+        0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0xff00000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1020408000000000, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x1020408000000000, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x1020408000000000, 0x0, 0x0, 0x8080808080808080,
+    ],
+    [
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x10204081020, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x10204081020, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x10204081020, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x10204081020, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x10204081020, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000,
+        0x101010101010101, 0x402010000000000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x402010000000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x1020408102040, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x1020408102040, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x1020408102040, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x1020408102040, 0x0, 0x0, 0x0, 0x0,
+        0x804020100000000, 0x202020202020202, 0x1020408102040, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xff0000000000, 0x0, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000,
+        0x1020408102040, 0x202020202020202, 0x804020100000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x804020100000000, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x102040810204080,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x102040810204080, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x102040810204080, 0x0, 0x0,
+        0x1008040201000000, 0x0, 0x404040404040404, 0x0, 0x102040810204080, 0x0, 0x0, 0x0,
+        0x0, 0x1008040201000000, 0x404040404040404, 0x102040810204080, 0x0, 0x0, 0x0, 0x0,
+        0xff0000000000, 0xff0000000000, 0x0, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000,
+        0x0, 0x102040810204080, 0x404040404040404, 0x1008040201000000, 0x0, 0x0, 0x0, 0x0,
+        0x102040810204080, 0x0, 0x404040404040404, 0x0, 0x1008040201000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x204081020408000,
+        0x2010080402010000, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x204081020408000, 0x0,
+        0x0, 0x2010080402010000, 0x0, 0x808080808080808, 0x0, 0x204081020408000, 0x0, 0x0,
+        0x0, 0x0, 0x2010080402010000, 0x808080808080808, 0x204081020408000, 0x0, 0x0, 0x0,
+        0xff0000000000, 0xff0000000000, 0xff0000000000, 0x0, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000,
+        0x0, 0x0, 0x204081020408000, 0x808080808080808, 0x2010080402010000, 0x0, 0x0, 0x0,
+        0x0, 0x204081020408000, 0x0, 0x808080808080808, 0x0, 0x2010080402010000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x4020100804020100, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x4020100804020100, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x408102040800000,
+        0x0, 0x0, 0x4020100804020100, 0x0, 0x1010101010101010, 0x0, 0x408102040800000, 0x0,
+        0x0, 0x0, 0x0, 0x4020100804020100, 0x1010101010101010, 0x408102040800000, 0x0, 0x0,
+        0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0x0, 0xff0000000000, 0xff0000000000, 0xff0000000000,
+        0x0, 0x0, 0x0, 0x408102040800000, 0x1010101010101010, 0x4020100804020100, 0x0, 0x0,
+        0x0, 0x0, 0x408102040800000, 0x0, 0x1010101010101010, 0x0, 0x4020100804020100, 0x0,
+    ],
+    [
+        0x8040201008040201, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x8040201008040201, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x8040201008040201, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x8040201008040201, 0x0, 0x2020202020202020, 0x0, 0x810204080000000,
+        0x0, 0x0, 0x0, 0x0, 0x8040201008040201, 0x2020202020202020, 0x810204080000000, 0x0,
+        0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0x0, 0xff0000000000, 0xff0000000000,
+        0x0, 0x0, 0x0, 0x0, 0x810204080000000, 0x2020202020202020, 0x8040201008040201, 0x0,
+        0x0, 0x0, 0x0, 0x810204080000000, 0x0, 0x2020202020202020, 0x0, 0x8040201008040201,
+    ],
+    [
+        0x0, 0x80402010080402, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x80402010080402, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x80402010080402, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x80402010080402, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x80402010080402, 0x4040404040404040, 0x1020408000000000,
+        0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0x0, 0xff0000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x1020408000000000, 0x4040404040404040, 0x80402010080402,
+        0x0, 0x0, 0x0, 0x0, 0x1020408000000000, 0x0, 0x4040404040404040, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x804020100804, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x804020100804, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x804020100804, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x804020100804, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x804020100804, 0x8080808080808080,
+        0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0xff0000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2040800000000000, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x2040800000000000, 0x0, 0x8080808080808080,
+    ],
+    [
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1020408102040, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x1020408102040, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x1020408102040, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x1020408102040, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x1020408102040, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x1020408102040, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000,
+        0x101010101010101, 0x201000000000000, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x0, 0x102040810204080,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x102040810204080, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x102040810204080, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x102040810204080, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x102040810204080, 0x0, 0x0, 0x0, 0x0,
+        0x402010000000000, 0x202020202020202, 0x102040810204080, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xff000000000000, 0x0, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000,
+        0x102040810204080, 0x202020202020202, 0x402010000000000, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x204081020408000,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x204081020408000, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x204081020408000, 0x0, 0x0,
+        0x804020100000000, 0x0, 0x404040404040404, 0x0, 0x204081020408000, 0x0, 0x0, 0x0,
+        0x0, 0x804020100000000, 0x404040404040404, 0x204081020408000, 0x0, 0x0, 0x0, 0x0,
+        0xff000000000000, 0xff000000000000, 0x0, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000,
+        0x0, 0x204081020408000, 0x404040404040404, 0x804020100000000, 0x0, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x408102040800000,
+        0x1008040201000000, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x408102040800000, 0x0,
+        0x0, 0x1008040201000000, 0x0, 0x808080808080808, 0x0, 0x408102040800000, 0x0, 0x0,
+        0x0, 0x0, 0x1008040201000000, 0x808080808080808, 0x408102040800000, 0x0, 0x0, 0x0,
+        0xff000000000000, 0xff000000000000, 0xff000000000000, 0x0, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000,
+        0x0, 0x0, 0x408102040800000, 0x808080808080808, 0x1008040201000000, 0x0, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x2010080402010000, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x2010080402010000, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x810204080000000,
+        0x0, 0x0, 0x2010080402010000, 0x0, 0x1010101010101010, 0x0, 0x810204080000000, 0x0,
+        0x0, 0x0, 0x0, 0x2010080402010000, 0x1010101010101010, 0x810204080000000, 0x0, 0x0,
+        0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0x0, 0xff000000000000, 0xff000000000000, 0xff000000000000,
+        0x0, 0x0, 0x0, 0x810204080000000, 0x1010101010101010, 0x2010080402010000, 0x0, 0x0,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x4020100804020100, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x4020100804020100, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x4020100804020100, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x4020100804020100, 0x0, 0x2020202020202020, 0x0, 0x1020408000000000,
+        0x0, 0x0, 0x0, 0x0, 0x4020100804020100, 0x2020202020202020, 0x1020408000000000, 0x0,
+        0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0x0, 0xff000000000000, 0xff000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x1020408000000000, 0x2020202020202020, 0x4020100804020100, 0x0,
+    ],
+    [
+        0x8040201008040201, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x8040201008040201, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x8040201008040201, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x8040201008040201, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x8040201008040201, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x8040201008040201, 0x4040404040404040, 0x2040800000000000,
+        0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0x0, 0xff000000000000,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x2040800000000000, 0x4040404040404040, 0x8040201008040201,
+    ],
+    [
+        0x0, 0x80402010080402, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x80402010080402, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x80402010080402, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x80402010080402, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x80402010080402, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x80402010080402, 0x8080808080808080,
+        0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0xff000000000000, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4080000000000000, 0x8080808080808080,
+    ],
+    [
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x102040810204080,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x0, 0x102040810204080, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x0, 0x102040810204080, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x0, 0x102040810204080, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x0, 0x102040810204080, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x0, 0x102040810204080, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x101010101010101, 0x102040810204080, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000,
+    ],
+    [
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x0, 0x204081020408000,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x0, 0x204081020408000, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x0, 0x204081020408000, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x0, 0x204081020408000, 0x0, 0x0, 0x0,
+        0x0, 0x202020202020202, 0x0, 0x204081020408000, 0x0, 0x0, 0x0, 0x0,
+        0x201000000000000, 0x202020202020202, 0x204081020408000, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0xff00000000000000, 0x0, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000,
+    ],
+    [
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x0, 0x408102040800000,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x0, 0x408102040800000, 0x0,
+        0x0, 0x0, 0x404040404040404, 0x0, 0x0, 0x408102040800000, 0x0, 0x0,
+        0x402010000000000, 0x0, 0x404040404040404, 0x0, 0x408102040800000, 0x0, 0x0, 0x0,
+        0x0, 0x402010000000000, 0x404040404040404, 0x408102040800000, 0x0, 0x0, 0x0, 0x0,
+        0xff00000000000000, 0xff00000000000000, 0x0, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x0, 0x810204080000000,
+        0x804020100000000, 0x0, 0x0, 0x808080808080808, 0x0, 0x0, 0x810204080000000, 0x0,
+        0x0, 0x804020100000000, 0x0, 0x808080808080808, 0x0, 0x810204080000000, 0x0, 0x0,
+        0x0, 0x0, 0x804020100000000, 0x808080808080808, 0x810204080000000, 0x0, 0x0, 0x0,
+        0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0x0, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x1008040201000000, 0x0, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x0,
+        0x0, 0x1008040201000000, 0x0, 0x0, 0x1010101010101010, 0x0, 0x0, 0x1020408000000000,
+        0x0, 0x0, 0x1008040201000000, 0x0, 0x1010101010101010, 0x0, 0x1020408000000000, 0x0,
+        0x0, 0x0, 0x0, 0x1008040201000000, 0x1010101010101010, 0x1020408000000000, 0x0, 0x0,
+        0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0x0, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x2010080402010000, 0x0, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x2010080402010000, 0x0, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x2010080402010000, 0x0, 0x0, 0x2020202020202020, 0x0, 0x0,
+        0x0, 0x0, 0x0, 0x2010080402010000, 0x0, 0x2020202020202020, 0x0, 0x2040800000000000,
+        0x0, 0x0, 0x0, 0x0, 0x2010080402010000, 0x2020202020202020, 0x2040800000000000, 0x0,
+        0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0x0, 0xff00000000000000, 0xff00000000000000,
+    ],
+    [
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x4020100804020100, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x4020100804020100, 0x0, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x4020100804020100, 0x0, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x4020100804020100, 0x0, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x4020100804020100, 0x0, 0x4040404040404040, 0x0,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x4020100804020100, 0x4040404040404040, 0x4080000000000000,
+        0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0x0, 0xff00000000000000,
+    ],
+    [
+        0x8040201008040201, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x8040201008040201, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x8040201008040201, 0x0, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x8040201008040201, 0x0, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x8040201008040201, 0x0, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x8040201008040201, 0x0, 0x8080808080808080,
+        0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x8040201008040201, 0x8080808080808080,
+        0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0xff00000000000000, 0x0,
+    ],
+];
+
+pub static ROOK_MAGICS: [u64; 64] = [
+    0xa80004000801220, 0x8040004010002008, 0x2080200010008008, 0x1100100008210004, 0xc200209084020008, 0x2100010004000208, 0x400081000822421, 0x200010422048844,
+    0x800800080400024, 0x1402000401000, 0x3000801000802001, 0x4400800800100083, 0x904802402480080, 0x4040800400020080, 0x18808042000100, 0x4040800080004100,
+    0x40048001458024, 0xa0004000205000, 0x3100808010002000, 0x4825010010000820, 0x5004808008000401, 0x2024818004000a00, 0x5808002000100, 0x2100060004806104,
+    0x80400880008421, 0x4062220600410280, 0x10a004a00108022, 0x100080080080, 0x21000500080010, 0x44000202001008, 0x100400080102, 0xc020128200040545,
+    0x80002000400040, 0x804000802004, 0x120022004080, 0x10a386103001001, 0x9010080080800400, 0x8440020080800400, 0x4228824001001, 0x490a000084,
+    0x80002000504000, 0x200020005000c000, 0x12088020420010, 0x10010080080800, 0x85001008010004, 0x2000204008080, 0x40413002040008, 0x304081020004,
+    0x80204000800080, 0x3008804000290100, 0x1010100080200080, 0x2008100208028080, 0x5000850800910100, 0x8402019004680200, 0x120911028020400, 0x8044010200,
+    0x20850200244012, 0x20850200244012, 0x102001040841, 0x140900040a100021, 0x200282410a102, 0x200282410a102, 0x200282410a102, 0x4048240043802106,
+];
+
+pub static BISHOP_MAGICS: [u64; 64] = [
+    0x40106000a1160020, 0x20010250810120, 0x2010010220280081, 0x2806004050c040, 0x2021018000000, 0x2001112010000400, 0x881010120218080, 0x1030820110010500,
+    0x120222042400, 0x2000020404040044, 0x8000480094208000, 0x3422a02000001, 0xa220210100040, 0x8004820202226000, 0x18234854100800, 0x100004042101040,
+    0x4001004082820, 0x10000810010048, 0x1014004208081300, 0x2080818802044202, 0x40880c00a00100, 0x80400200522010, 0x1000188180b04, 0x80249202020204,
+    0x1004400004100410, 0x13100a0022206, 0x2148500001040080, 0x4241080011004300, 0x4020848004002000, 0x10101380d1004100, 0x8004422020284, 0x1010a1041008080,
+    0x808080400082121, 0x808080400082121, 0x91128200100c00, 0x202200802010104, 0x8c0a020200440085, 0x1a0008080b10040, 0x889520080122800, 0x100902022202010a,
+    0x4081a0816002000, 0x681208005000, 0x8170840041008802, 0xa00004200810805, 0x830404408210100, 0x2602208106006102, 0x1048300680802628, 0x2602208106006102,
+    0x602010120110040, 0x941010801043000, 0x40440a210428, 0x8240020880021, 0x400002012048200, 0xac102001210220, 0x220021002009900, 0x84440c080a013080,
+    0x1008044200440, 0x4c04410841000, 0x2000500104011130, 0x1a0c010011c20229, 0x44800112202200, 0x434804908100424, 0x300404822c08200, 0x48081010008a2a80,
+];
@@ -0,0 +1,550 @@
+#![warn(clippy::pedantic, clippy::nursery)]
+#![feature(iter_intersperse)]
+#![allow(dead_code)]
+
+use std::{env, fmt::Write, fs, process::ExitCode, thread, time::Instant};
+
+use board::Board;
+use eval::{
+    Score, BISHOP_PAIR, DOUBLED_PAWN, ISOLATED_PAWN, KING_ATTACK, KING_SHIELD, MATERIAL, MAX_PHASE,
+    MOBILITY, PARAM_COUNT, PASSED_PAWN, PIECE_SQUARE, WEIGHTS,
+};
+use search::MATE;
+
+mod bitboard;
+mod board;
+mod clock;
+mod errors;
+mod eval;
+mod game;
+mod half_move;
+mod magic;
+mod nnue;
+mod piece;
+mod piece_map;
+mod pieces;
+mod player;
+mod position;
+mod search;
+#[rustfmt::skip]
+mod tables;
+mod zobrist;
+
+const USAGE: &str =
+    "usage: tune <positions> [--iterations <n>] [--rate <centipawns>] [--output <path>]
+
+Each line of <positions> holds a FEN followed by the game's result for White, as 1-0, 0-1 or
+1/2-1/2, or as 1.0, 0.5 or 0.0, optionally in brackets or quotes.";
+
+const PIECE_NAMES: [&str; 6] = ["pawn", "knight", "bishop", "rook", "queen", "king"];
+/// Adam's decay rates for the gradient's running mean and variance.
+const BETA1: f64 = 0.9;
+const BETA2: f64 = 0.999;
+const EPSILON: f64 = 1e-8;
+
+struct Options {
+    positions: String,
+    iterations: u32,
+    /// Adam's step size, roughly how far a weight may move per iteration, in centipawns.
+    rate: f64,
+    output: String,
+}
+
+/// A quiet position reduced to what the evaluation sees of it.
+struct Sample {
+    /// The parameters that apply and how often, counting White's positively.
+    features: Vec<(usize, f64)>,
+    /// How much of the middlegame weight applies, from 1 in the opening to 0 in a pawn ending.
+    phase: f64,
+    /// The game's result for White: 1 for a win, 0.5 for a draw and 0 for a loss.
+    result: f64,
+}
+
+impl Sample {
+    /// The tapered evaluation from White's point of view, with `weights` holding the middlegame
+    /// value of every parameter followed by every endgame value.
+    fn evaluate(&self, weights: &[f64]) -> f64 {
+        self.features
+            .iter()
+            .map(|&(param, count)| {
+                count
+                    * weights[param].mul_add(
+                        self.phase,
+                        weights[PARAM_COUNT + param] * (1.0 - self.phase),
+                    )
+            })
+            .sum()
+    }
+}
+
+/// Tunes the evaluation weights with Texel's method: each position is resolved to a quiet one
+/// with a quiescence search, and gradient descent then minimizes the squared difference between
+/// the game results and the evaluation mapped onto an expected result by a sigmoid.
+fn main() -> ExitCode {
+    let Some(options) = parse_options(env::args().skip(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+    let text = match fs::read_to_string(&options.positions) {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("cannot read {}: {error}", options.positions);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let start = Instant::now();
+    let samples = load_samples(&text);
+    if samples.is_empty() {
+        eprintln!("no usable positions in {}", options.positions);
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "Loaded {} quiet positions in {:.1?}",
+        samples.len(),
+        start.elapsed()
+    );
+
+    let mut weights: Vec<f64> = WEIGHTS
+        .iter()
+        .map(|score| f64::from(score.mg))
+        .chain(WEIGHTS.iter().map(|score| f64::from(score.eg)))
+        .collect();
+    let k = fit_scaling(&samples, &weights);
+    println!(
+        "Scaling constant {k:.3}, starting error {:.6}",
+        error(&samples, &weights, k)
+    );
+
+    let mut mean = vec![0.0; weights.len()];
+    let mut variance = vec![0.0; weights.len()];
+    for iteration in 1..=options.iterations {
+        let gradient = gradient(&samples, &weights, k);
+        let step = f64::from(iteration);
+        for (param, weight) in weights.iter_mut().enumerate() {
+            mean[param] = BETA1.mul_add(mean[param], (1.0 - BETA1) * gradient[param]);
+            variance[param] =
+                BETA2.mul_add(variance[param], (1.0 - BETA2) * gradient[param].powi(2));
+            let mean = mean[param] / (1.0 - BETA1.powf(step));
+            let variance = variance[param] / (1.0 - BETA2.powf(step));
+            *weight -= options.rate * mean / (variance.sqrt() + EPSILON);
+        }
+        if iteration % 100 == 0 || iteration == options.iterations {
+            println!(
+                "Iteration {iteration}: error {:.6}",
+                error(&samples, &weights, k)
+            );
+        }
+    }
+
+    // Tuned weights stay within a few thousand centipawns, far inside an i32.
+    #[allow(clippy::cast_possible_truncation)]
+    let tuned: Vec<Score> = (0..PARAM_COUNT)
+        .map(|param| {
+            Score::new(
+                weights[param].round() as i32,
+                weights[PARAM_COUNT + param].round() as i32,
+            )
+        })
+        .collect();
+    if let Err(error) = fs::write(&options.output, weights_source(&tuned)) {
+        eprintln!("cannot write {}: {error}", options.output);
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "Wrote weights to {} in {:.1?}",
+        options.output,
+        start.elapsed()
+    );
+    ExitCode::SUCCESS
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Option<Options> {
+    let mut options = Options {
+        positions: String::new(),
+        iterations: 1000,
+        rate: 1.0,
+        output: concat!(env!("CARGO_MANIFEST_DIR"), "/src/eval/weights.rs").to_string(),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => options.iterations = args.next()?.parse().ok()?,
+            "--rate" => options.rate = args.next()?.parse().ok()?,
+            "--output" => options.output = args.next()?,
+            _ if options.positions.is_empty() && !arg.starts_with("--") => options.positions = arg,
+            _ => return None,
+        }
+    }
+    (!options.positions.is_empty()).then_some(options)
+}
+
+/// Parses every labelled position, skipping lines that do not parse and positions whose
+/// quiescence search ends in mate, as no evaluation explains those.
+fn load_samples(text: &str) -> Vec<Sample> {
+    let mut skipped = 0;
+    let samples: Vec<Sample> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let sample = parse_line(line).and_then(|(board, result)| sample(board, result));
+            if sample.is_none() {
+                skipped += 1;
+            }
+            sample
+        })
+        .collect();
+    if skipped > 0 {
+        eprintln!("Skipped {skipped} lines");
+    }
+    samples
+}
+
+/// Splits a line into its position and result. Bare `1` and `0` are not results, so that the
+/// move counters of a FEN without one are not mistaken for it.
+fn parse_line(line: &str) -> Option<(Board, f64)> {
+    let (fen, result) = line.trim().rsplit_once(char::is_whitespace)?;
+    let result = match result.trim_matches(|c| matches!(c, '[' | ']' | '"' | ';')) {
+        "1-0" | "1.0" => 1.0,
+        "0-1" | "0.0" => 0.0,
+        "1/2-1/2" | "0.5" => 0.5,
+        _ => return None,
+    };
+    // EPD lines mark the result with a `c9` opcode and leave out the move counters.
+    let fields: Vec<&str> = fen
+        .split_whitespace()
+        .take_while(|&field| field != "c9")
+        .collect();
+    let counters = ["0", "1"];
+    let fen = fields
+        .iter()
+        .copied()
+        .chain(
+            counters[fields.len().saturating_sub(4).min(2)..]
+                .iter()
+                .copied(),
+        )
+        .intersperse(" ")
+        .collect::<String>();
+    Some((Board::from_fen(&fen).ok()?, result))
+}
+
+fn sample(mut board: Board, result: f64) -> Option<Sample> {
+    let (score, line) = search::quiesce(&board);
+    if score.abs() > MATE - 1000 {
+        return None;
+    }
+    for half_move in &line {
+        board.make_move(half_move);
+    }
+
+    let mut features = Vec::new();
+    eval::for_each_feature(&board, |param, count| {
+        features.push((param, f64::from(count)));
+    });
+    Some(Sample {
+        features,
+        phase: f64::from(eval::phase(&board)) / f64::from(MAX_PHASE),
+        result,
+    })
+}
+
+/// The expected result for an evaluation in centipawns.
+fn sigmoid(k: f64, evaluation: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-k * evaluation / 400.0))
+}
+
+/// The mean squared difference between the results and the expected results.
+// Sample counts stay far below 2^52, where the conversion would start rounding.
+#[allow(clippy::cast_precision_loss)]
+fn error(samples: &[Sample], weights: &[f64], k: f64) -> f64 {
+    let total: f64 = in_parallel(samples, |chunk| {
+        chunk
+            .iter()
+            .map(|sample| (sample.result - sigmoid(k, sample.evaluate(weights))).powi(2))
+            .sum::<f64>()
+    })
+    .into_iter()
+    .sum();
+    total / samples.len() as f64
+}
+
+/// The derivative of [`error`] with respect to every weight.
+#[allow(clippy::cast_precision_loss)]
+fn gradient(samples: &[Sample], weights: &[f64], k: f64) -> Vec<f64> {
+    let partials = in_parallel(samples, |chunk| {
+        let mut gradient = vec![0.0; weights.len()];
+        for sample in chunk {
+            let expected = sigmoid(k, sample.evaluate(weights));
+            let slope = (expected - sample.result)
+                * expected
+                * (1.0 - expected)
+                * k
+                * std::f64::consts::LN_10
+                / 400.0;
+            for &(param, count) in &sample.features {
+                gradient[param] = (slope * count).mul_add(sample.phase, gradient[param]);
+                gradient[PARAM_COUNT + param] =
+                    (slope * count).mul_add(1.0 - sample.phase, gradient[PARAM_COUNT + param]);
+            }
+        }
+        gradient
+    });
+
+    let scale = 2.0 / samples.len() as f64;
+    partials
+        .into_iter()
+        .reduce(|mut total, partial| {
+            for (total, partial) in total.iter_mut().zip(partial) {
+                *total += partial;
+            }
+            total
+        })
+        .map_or_else(Vec::new, |total| {
+            total.into_iter().map(|value| value * scale).collect()
+        })
+}
+
+/// Finds the sigmoid's scaling constant that best fits the starting weights, so tuning only
+/// moves the weights and not the scale of the evaluation.
+fn fit_scaling(samples: &[Sample], weights: &[f64]) -> f64 {
+    let (mut low, mut high) = (0.0, 4.0);
+    for _ in 0..40 {
+        let third = (high - low) / 3.0;
+        if error(samples, weights, low + third) < error(samples, weights, high - third) {
+            high -= third;
+        } else {
+            low += third;
+        }
+    }
+    f64::midpoint(low, high)
+}
+
+/// Splits `samples` into one chunk per available core and maps each on its own thread.
+fn in_parallel<T: Send>(samples: &[Sample], map: impl Fn(&[Sample]) -> T + Sync) -> Vec<T> {
+    let threads = thread::available_parallelism().map_or(1, usize::from);
+    let chunk_size = samples.len().div_ceil(threads).max(1);
+    thread::scope(|scope| {
+        let handles: Vec<_> = samples
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| map(chunk)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("tuning threads do not panic"))
+            .collect()
+    })
+}
+
+/// Formats `weights` as the source of `src/eval/weights.rs`.
+fn weights_source(weights: &[Score]) -> String {
+    let mut groups = vec![(MATERIAL, format!("Material: {}", PIECE_NAMES.join(", ")))];
+    for (index, name) in PIECE_NAMES.iter().enumerate() {
+        groups.push((
+            PIECE_SQUARE + index * 64,
+            format!("Piece-square table: {name}, from a1 to h8"),
+        ));
+    }
+    groups.extend([
+        (
+            MOBILITY,
+            format!("Mobility per reachable square: {}", PIECE_NAMES.join(", ")),
+        ),
+        (DOUBLED_PAWN, "Doubled pawn".to_string()),
+        (ISOLATED_PAWN, "Isolated pawn".to_string()),
+        (
+            PASSED_PAWN,
+            "Passed pawn by relative rank, from 1 to 8".to_string(),
+        ),
+        (KING_SHIELD, "King shield pawn".to_string()),
+        (
+            KING_ATTACK,
+            "Attack on a square next to the king".to_string(),
+        ),
+        (BISHOP_PAIR, "Bishop pair".to_string()),
+        (PARAM_COUNT, String::new()),
+    ]);
+
+    let mut out = String::new();
+    out.push_str(
+        "//! Evaluation weights: a middlegame and endgame value for every parameter, in the order \
+         of the\n//! parameter offsets in `eval`. Generated by `cargo run --release --bin tune`, \
+         which starts\n//! from the weights here; hand edits are kept only as a starting point \
+         for the next run.\n\nuse super::{Score, PARAM_COUNT};\n\n#[rustfmt::skip]\npub static \
+         WEIGHTS: [Score; PARAM_COUNT] = [\n",
+    );
+    for window in groups.windows(2) {
+        let ((start, comment), (end, _)) = (&window[0], &window[1]);
+        writeln!(out, "    // {comment}").unwrap();
+        for row in weights[*start..*end].chunks(8) {
+            let values: String = row
+                .iter()
+                .map(|score| format!("Score::new({}, {}),", score.mg, score.eg))
+                .intersperse(" ".to_string())
+                .collect();
+            writeln!(out, "    {values}").unwrap();
+        }
+    }
+    out.push_str("];\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    const AFTER_E4: &str = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3";
+
+    fn parsed(line: &str) -> Option<(String, f64)> {
+        parse_line(line).map(|(board, result)| (board.to_fen(), result))
+    }
+
+    #[test]
+    fn parses_fen_and_epd_lines() {
+        let start = Some((START.to_string(), 1.0));
+        assert_eq!(parsed(&format!("{START} 1-0")), start);
+        assert_eq!(parsed(&format!("{START} [1.0]")), start);
+        assert_eq!(parsed(&format!("  {START}\t\"1-0\"  ")), start);
+        assert_eq!(parsed(&format!("{START} 0-1")).unwrap().1, 0.0);
+        assert_eq!(parsed(&format!("{START} [0.0]")).unwrap().1, 0.0);
+        assert_eq!(parsed(&format!("{START} 1/2-1/2")).unwrap().1, 0.5);
+        assert_eq!(parsed(&format!("{START} \"0.5\"")).unwrap().1, 0.5);
+
+        // EPD records leave out the counters and carry the result in a `c9` opcode.
+        let after_e4 = Some((format!("{AFTER_E4} 0 1"), 0.5));
+        assert_eq!(parsed(&format!("{AFTER_E4} c9 \"1/2-1/2\";")), after_e4);
+        assert_eq!(parsed(&format!("{AFTER_E4} [0.5]")), after_e4);
+    }
+
+    #[test]
+    fn rejects_bad_lines() {
+        for line in [
+            String::new(),
+            "1-0".to_string(),
+            START.to_string(),
+            format!("{START} 2-0"),
+            format!("{START} 1"),
+            format!("{START} 0"),
+            format!("{START} win"),
+            "rnbqkbnr/pppppppp/8/8 w KQkq - 0 1 1-0".to_string(),
+            format!("{AFTER_E4} c9 \"1-1\";"),
+        ] {
+            assert!(parse_line(&line).is_none(), "'{line}' parsed");
+        }
+    }
+
+    #[test]
+    fn reads_options() {
+        let args = |line: &str| {
+            line.split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        let options =
+            parse_options(args("games.epd --iterations 50 --rate 0.5 --output out.rs").into_iter())
+                .expect("the options are valid");
+        assert_eq!(options.positions, "games.epd");
+        assert_eq!(options.iterations, 50);
+        assert!((options.rate - 0.5).abs() < f64::EPSILON);
+        assert_eq!(options.output, "out.rs");
+
+        for line in [
+            "",
+            "--iterations 5",
+            "a.epd b.epd",
+            "a.epd --rate",
+            "a.epd --iterations x",
+            "a.epd --verbose",
+        ] {
+            assert!(
+                parse_options(args(line).into_iter()).is_none(),
+                "'{line}' parsed"
+            );
+        }
+    }
+
+    fn starting_weights() -> Vec<f64> {
+        WEIGHTS
+            .iter()
+            .map(|score| f64::from(score.mg))
+            .chain(WEIGHTS.iter().map(|score| f64::from(score.eg)))
+            .collect()
+    }
+
+    #[test]
+    fn gradient_matches_finite_differences() {
+        let samples: Vec<Sample> = [
+            (START, 0.5),
+            (
+                "r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R b KQkq - 3 3",
+                1.0,
+            ),
+            ("8/5pk1/6p1/8/3P4/6P1/5PK1/8 w - - 0 40", 1.0),
+            (
+                "r3k2r/pp3ppp/2n5/3q4/3P4/2N5/PP3PPP/R2QR1K1 w kq - 0 14",
+                0.0,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(fen, result)| sample(Board::from_fen(fen).unwrap(), result))
+        .collect();
+        assert_eq!(samples.len(), 4);
+
+        let weights = starting_weights();
+        let k = 1.3;
+        let analytic = gradient(&samples, &weights, k);
+        assert_eq!(analytic.len(), weights.len());
+
+        let step = 0.01;
+        let mut checked = 0;
+        for (param, &expected) in analytic.iter().enumerate() {
+            let mut nudged = weights.clone();
+            nudged[param] = weights[param] + step;
+            let above = error(&samples, &nudged, k);
+            nudged[param] = weights[param] - step;
+            let below = error(&samples, &nudged, k);
+            let numeric = (above - below) / (2.0 * step);
+            assert!(
+                (numeric - expected).abs() <= 1e-4f64.mul_add(expected.abs(), 1e-10),
+                "parameter {param}: analytic {expected:e}, numeric {numeric:e}"
+            );
+            if expected != 0.0 {
+                checked += 1;
+            }
+        }
+        assert!(checked > 50, "only {checked} parameters had a gradient");
+    }
+
+    /// Every `Score::new(mg, eg)` in `source`, in order.
+    fn scores_in(source: &str) -> Vec<Score> {
+        source
+            .split("Score::new(")
+            .skip(1)
+            .filter_map(|rest| {
+                let (mg, rest) = rest.split_once(", ")?;
+                let (eg, _) = rest.split_once(')')?;
+                Some(Score::new(mg.parse().ok()?, eg.parse().ok()?))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn weights_source_reads_back_the_same_weights() {
+        let weights: Vec<Score> = (0..PARAM_COUNT)
+            .map(|param| {
+                let param = i32::try_from(param).unwrap();
+                Score::new(param * 7 - 1000, 50 - param)
+            })
+            .collect();
+        let source = weights_source(&weights);
+        assert_eq!(scores_in(&source), weights);
+        assert!(source.contains("pub static WEIGHTS: [Score; PARAM_COUNT] = ["));
+        assert!(source.ends_with("];\n"));
+
+        // The checked-in weights are laid out exactly as the tuner writes them.
+        let checked_in = include_str!("eval/weights.rs");
+        assert_eq!(scores_in(checked_in), WEIGHTS);
+        let body = |source: &str| source[source.find("use super").unwrap()..].to_string();
+        assert_eq!(body(&weights_source(&WEIGHTS)), body(checked_in));
+    }
+}
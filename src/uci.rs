@@ -0,0 +1,505 @@
+#![warn(clippy::pedantic, clippy::nursery)]
+#![feature(iter_intersperse)]
+#![allow(dead_code)]
+
+use std::{
+    io::{self, BufRead},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use board::Board;
+use nnue::Network;
+use piece::Color;
+use search::{SearchInfo, SearchLimits, TranspositionTable};
+
+mod bitboard;
+mod board;
+mod clock;
+mod errors;
+mod eval;
+mod game;
+mod half_move;
+mod magic;
+mod nnue;
+mod piece;
+mod piece_map;
+mod pieces;
+mod player;
+mod position;
+mod search;
+#[rustfmt::skip]
+mod tables;
+mod zobrist;
+
+const ENGINE_NAME: &str = concat!("chess-two ", env!("CARGO_PKG_VERSION"));
+const MOVE_OVERHEAD: Spin = Spin {
+    name: "Move Overhead",
+    default: 10,
+    min: 0,
+    max: 5000,
+};
+const EVAL_FILE: &str = "EvalFile";
+const HASH: Spin = Spin {
+    name: "Hash",
+    default: TranspositionTable::DEFAULT_MEGABYTES as u64,
+    min: 1,
+    max: 65536,
+};
+const THREADS: Spin = Spin {
+    name: "Threads",
+    default: 1,
+    min: 1,
+    max: 256,
+};
+
+/// A numeric UCI option.
+struct Spin {
+    name: &'static str,
+    default: u64,
+    min: u64,
+    max: u64,
+}
+
+impl Spin {
+    fn declaration(&self) -> String {
+        format!(
+            "option name {} type spin default {} min {} max {}",
+            self.name, self.default, self.min, self.max
+        )
+    }
+
+    fn parse(&self, value: &str) -> Result<u64, String> {
+        value
+            .parse()
+            .ok()
+            .filter(|value| (self.min..=self.max).contains(value))
+            .ok_or_else(|| {
+                format!(
+                    "{} must be a number from {} to {}",
+                    self.name, self.min, self.max
+                )
+            })
+    }
+}
+
+/// Speaks the Universal Chess Interface on stdin and stdout, so the engine can be used from
+/// GUIs and testing harnesses.
+fn main() {
+    let mut engine = Engine::new();
+    for line in io::stdin().lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match tokens.split_first() {
+            Some((&"uci", _)) => Engine::identify(),
+            Some((&"isready", _)) => println!("readyok"),
+            Some((&"ucinewgame", _)) => engine.new_game(),
+            Some((&"position", arguments)) => engine.set_position(arguments),
+            Some((&"go", arguments)) => engine.go(arguments),
+            Some((&"stop", _)) => engine.stop(),
+            Some((&"setoption", arguments)) => engine.set_option(arguments),
+            Some((&"eval", _)) => println!("{}", eval::explain(&engine.board)),
+            Some((&"quit", _)) => break,
+            Some((command, _)) => println!("info string unknown command '{command}'"),
+            None => {}
+        }
+    }
+    engine.stop();
+}
+
+struct Engine {
+    board: Board,
+    move_overhead: Duration,
+    threads: usize,
+    table: Arc<TranspositionTable>,
+    /// The network to evaluate with, if `EvalFile` names one.
+    network: Option<Arc<Network>>,
+    stop: Arc<AtomicBool>,
+    search: Option<JoinHandle<()>>,
+}
+
+impl Engine {
+    fn new() -> Self {
+        Self {
+            board: Board::new(),
+            move_overhead: Duration::from_millis(MOVE_OVERHEAD.default),
+            threads: 1,
+            table: Arc::default(),
+            network: None,
+            stop: Arc::new(AtomicBool::new(false)),
+            search: None,
+        }
+    }
+
+    fn identify() {
+        println!("id name {ENGINE_NAME}");
+        println!("id author the chess-two authors");
+        println!("option name {EVAL_FILE} type string default <empty>");
+        println!("{}", HASH.declaration());
+        println!("{}", MOVE_OVERHEAD.declaration());
+        println!("{}", THREADS.declaration());
+        println!("uciok");
+    }
+
+    fn new_game(&mut self) {
+        self.stop();
+        self.board = Board::new();
+        self.table.clear();
+    }
+
+    /// Handles `position [startpos | fen <fen>] [moves <move>...]`. A position that fails to
+    /// parse is reported and leaves the current one in place.
+    fn set_position(&mut self, arguments: &[&str]) {
+        match Self::parse_position(arguments) {
+            Ok(board) => self.board = board,
+            Err(reason) => println!("info string {reason}"),
+        }
+    }
+
+    fn parse_position(arguments: &[&str]) -> Result<Board, String> {
+        let moves_index = arguments
+            .iter()
+            .position(|&token| token == "moves")
+            .unwrap_or(arguments.len());
+        let (setup, moves) = arguments.split_at(moves_index);
+
+        let mut board = match setup.split_first() {
+            Some((&"startpos", [])) => Board::new(),
+            Some((&"fen", fen)) => Board::from_fen(&fen.join(" "))
+                .map_err(|error| format!("invalid FEN: {}", error.reason))?,
+            _ => return Err("expected 'startpos' or 'fen <fen>'".to_string()),
+        };
+        for text in moves.iter().skip(1) {
+            let half_move = board.parse_uci(text).map_err(|error| error.reason)?;
+            board.make_move(&half_move);
+        }
+        Ok(board)
+    }
+
+    /// Starts searching the current position in the background. The search prints `info`
+    /// lines as it deepens and `bestmove` when it finishes.
+    fn go(&mut self, arguments: &[&str]) {
+        self.stop();
+        let limits = self.parse_limits(arguments);
+        let board = self.board.clone();
+        let table = Arc::clone(&self.table);
+        let network = self.network.clone();
+        let stop = Arc::clone(&self.stop);
+        stop.store(false, Ordering::Relaxed);
+
+        self.search = Some(thread::spawn(move || {
+            let best_move =
+                search::search(&board, &limits, &table, network.as_deref(), &stop, |info| {
+                    println!("{}", format_info(info));
+                });
+            // In infinite mode the GUI expects no bestmove until it has sent `stop`.
+            while limits.infinite && !stop.load(Ordering::Relaxed) {
+                thread::park();
+            }
+            match best_move {
+                Some(best_move) => println!("bestmove {best_move}"),
+                None => println!("bestmove 0000"),
+            }
+        }));
+    }
+
+    fn parse_limits(&self, arguments: &[&str]) -> SearchLimits {
+        let mut limits = SearchLimits {
+            move_overhead: self.move_overhead,
+            threads: self.threads,
+            ..SearchLimits::default()
+        };
+        let (time, increment) = match self.board.current_turn() {
+            Color::White => ("wtime", "winc"),
+            Color::Black => ("btime", "binc"),
+        };
+        let millis = |value: &str| value.parse().ok().map(Duration::from_millis);
+
+        let mut tokens = arguments.iter().copied();
+        while let Some(token) = tokens.next() {
+            if token == "infinite" {
+                limits.infinite = true;
+                continue;
+            }
+            let Some(value) = tokens.clone().next() else {
+                break;
+            };
+            match token {
+                "depth" => limits.depth = value.parse().ok(),
+                "nodes" => limits.nodes = value.parse().ok(),
+                "movetime" => limits.movetime = millis(value),
+                "movestogo" => limits.moves_to_go = value.parse().ok(),
+                _ if token == time => limits.time_left = millis(value),
+                _ if token == increment => limits.increment = millis(value).unwrap_or_default(),
+                _ => continue,
+            }
+            tokens.next();
+        }
+        limits
+    }
+
+    /// Stops any running search and waits for it to print its `bestmove`.
+    fn stop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(search) = self.search.take() {
+            search.thread().unpark();
+            let _ = search.join();
+        }
+    }
+
+    /// Switches to the network in `path`, or back to the hand-crafted evaluation when the path
+    /// is empty.
+    fn load_network(&mut self, path: &str) -> Result<(), String> {
+        if path.is_empty() || path == "<empty>" {
+            self.network = None;
+            return Ok(());
+        }
+        let network = Network::load(path).map_err(|error| error.reason)?;
+        println!("info string loaded network {path}");
+        self.network = Some(Arc::new(network));
+        Ok(())
+    }
+
+    /// Handles `setoption name <name> [value <value>]`, reporting an unknown name or a bad
+    /// value without changing anything.
+    fn set_option(&mut self, arguments: &[&str]) {
+        if let Err(reason) = self.apply_option(arguments) {
+            println!("info string {reason}");
+        }
+    }
+
+    fn apply_option(&mut self, arguments: &[&str]) -> Result<(), String> {
+        let value_index = arguments
+            .iter()
+            .position(|&token| token == "value")
+            .unwrap_or(arguments.len());
+        let name = arguments[..value_index]
+            .iter()
+            .skip_while(|&&token| token == "name")
+            .copied()
+            .intersperse(" ")
+            .collect::<String>();
+        let value = arguments
+            .get(value_index + 1..)
+            .unwrap_or_default()
+            .join(" ");
+
+        if name.eq_ignore_ascii_case(EVAL_FILE) {
+            self.stop();
+            self.load_network(&value)
+        } else if name.eq_ignore_ascii_case(HASH.name) {
+            HASH.parse(&value).map(|megabytes| {
+                let megabytes = usize::try_from(megabytes).expect("the Hash maximum fits a usize");
+                self.stop();
+                self.table = Arc::new(TranspositionTable::new(megabytes));
+            })
+        } else if name.eq_ignore_ascii_case(THREADS.name) {
+            THREADS.parse(&value).map(|threads| {
+                self.threads = usize::try_from(threads).expect("the Threads maximum fits a usize");
+            })
+        } else if name.eq_ignore_ascii_case(MOVE_OVERHEAD.name) {
+            MOVE_OVERHEAD
+                .parse(&value)
+                .map(|millis| self.move_overhead = Duration::from_millis(millis))
+        } else {
+            Err(format!("unknown option '{name}'"))
+        }
+    }
+}
+
+fn format_info(info: &SearchInfo) -> String {
+    let score = info.mate_in().map_or_else(
+        || format!("cp {}", info.score),
+        |moves| format!("mate {moves}"),
+    );
+    let millis = info.elapsed.as_millis();
+    let nps = u128::from(info.nodes) * 1000 / millis.max(1);
+    let pv: String = info
+        .pv
+        .iter()
+        .map(ToString::to_string)
+        .intersperse(" ".to_string())
+        .collect();
+    format!(
+        "info depth {} score {score} nodes {} nps {nps} hashfull {} time {millis} pv {pv}",
+        info.depth, info.nodes, info.hashfull
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITALIAN: &str = "r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R b KQkq - 3 3";
+
+    fn position(command: &str) -> Result<Board, String> {
+        let arguments: Vec<&str> = command.split_whitespace().collect();
+        Engine::parse_position(&arguments)
+    }
+
+    fn limits(engine: &Engine, command: &str) -> SearchLimits {
+        let arguments: Vec<&str> = command.split_whitespace().collect();
+        engine.parse_limits(&arguments)
+    }
+
+    fn set_option(engine: &mut Engine, command: &str) -> Result<(), String> {
+        let arguments: Vec<&str> = command.split_whitespace().collect();
+        engine.apply_option(&arguments)
+    }
+
+    #[test]
+    fn sets_up_the_start_position_and_plays_moves() {
+        let board = position("startpos").unwrap();
+        assert!(board == Board::new());
+
+        let board = position("startpos moves e2e4 e7e5 g1f3 b8c6 f1c4").unwrap();
+        assert_eq!(board.to_fen(), ITALIAN);
+    }
+
+    #[test]
+    fn sets_up_a_fen_and_plays_moves() {
+        let board = position(&format!("fen {ITALIAN}")).unwrap();
+        assert_eq!(board.to_fen(), ITALIAN);
+
+        let board = position(&format!("fen {ITALIAN} moves g8f6 e1g1")).unwrap();
+        assert_eq!(
+            board.to_fen(),
+            "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQ1RK1 b kq - 5 4"
+        );
+        // Chess960 castling notation works in a move list too.
+        let board = position(&format!("fen {ITALIAN} moves g8f6 e1h1")).unwrap();
+        assert_eq!(
+            board.to_fen(),
+            "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQ1RK1 b kq - 5 4"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_positions() {
+        for command in [
+            "",
+            "startpos extra",
+            "somewhere",
+            "fen",
+            "fen 8/8/8/8 w - - 0 1",
+            "startpos moves e2e5",
+            "startpos moves e2e4 e2e4",
+            "startpos moves e2e4 nonsense",
+        ] {
+            assert!(position(command).is_err(), "'{command}' was accepted");
+        }
+    }
+
+    #[test]
+    fn set_position_keeps_the_old_board_on_errors() {
+        let mut engine = Engine::new();
+        engine.set_position(&["startpos", "moves", "e2e4"]);
+        let after_e4 = engine.board.to_fen();
+        engine.set_position(&["startpos", "moves", "e2e5"]);
+        assert_eq!(engine.board.to_fen(), after_e4);
+    }
+
+    #[test]
+    fn reads_the_clock_of_the_side_to_move() {
+        let mut engine = Engine::new();
+        let command = "wtime 60000 btime 30000 winc 1000 binc 500 movestogo 20";
+        let white = limits(&engine, command);
+        assert_eq!(white.time_left, Some(Duration::from_secs(60)));
+        assert_eq!(white.increment, Duration::from_secs(1));
+        assert_eq!(white.moves_to_go, Some(20));
+        assert_eq!(
+            white.move_overhead,
+            Duration::from_millis(MOVE_OVERHEAD.default)
+        );
+        assert!(!white.infinite);
+
+        engine.set_position(&["startpos", "moves", "e2e4"]);
+        let black = limits(&engine, command);
+        assert_eq!(black.time_left, Some(Duration::from_secs(30)));
+        assert_eq!(black.increment, Duration::from_millis(500));
+        assert_eq!(black.moves_to_go, Some(20));
+    }
+
+    #[test]
+    fn reads_fixed_limits() {
+        let engine = Engine::new();
+        let fixed = limits(&engine, "depth 7 nodes 100000 movetime 2500");
+        assert_eq!(fixed.depth, Some(7));
+        assert_eq!(fixed.nodes, Some(100_000));
+        assert_eq!(fixed.movetime, Some(Duration::from_millis(2500)));
+        assert_eq!(fixed.time_left, None);
+
+        let infinite = limits(&engine, "infinite");
+        assert!(infinite.infinite);
+        assert_eq!(infinite.depth, None);
+        assert_eq!(infinite.movetime, None);
+
+        // Unknown tokens and bad values are skipped rather than derailing the rest.
+        let sloppy = limits(
+            &engine,
+            "ponder depth x searchmoves e2e4 movetime 100 depth",
+        );
+        assert_eq!(sloppy.depth, None);
+        assert_eq!(sloppy.movetime, Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn applies_valid_options() {
+        let mut engine = Engine::new();
+        set_option(&mut engine, "name Threads value 4").unwrap();
+        assert_eq!(engine.threads, 4);
+        assert_eq!(limits(&engine, "depth 1").threads, 4);
+
+        set_option(&mut engine, "name Move Overhead value 250").unwrap();
+        assert_eq!(engine.move_overhead, Duration::from_millis(250));
+
+        let table = Arc::clone(&engine.table);
+        set_option(&mut engine, "name hash value 2").unwrap();
+        assert!(!Arc::ptr_eq(&table, &engine.table));
+
+        set_option(&mut engine, "name EvalFile value <empty>").unwrap();
+        assert!(engine.network.is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_options_and_bad_values() {
+        let mut engine = Engine::new();
+        let table = Arc::clone(&engine.table);
+        for command in [
+            "name Ponder value true",
+            "name Contempt value 10",
+            "",
+            "name Hash value 0",
+            "name Hash value 65537",
+            "name Hash value lots",
+            "name Hash",
+            "name Threads value 0",
+            "name Threads value 257",
+            "name Threads value -1",
+            "name Move Overhead value 5001",
+            "name Move Overhead value 1.5",
+            "name EvalFile value /nonexistent/network.nnue",
+        ] {
+            assert!(
+                set_option(&mut engine, command).is_err(),
+                "'{command}' was accepted"
+            );
+            // The printing wrapper must not panic either.
+            let arguments: Vec<&str> = command.split_whitespace().collect();
+            engine.set_option(&arguments);
+        }
+        assert!(Arc::ptr_eq(&table, &engine.table));
+        assert_eq!(engine.threads, 1);
+        assert_eq!(
+            engine.move_overhead,
+            Duration::from_millis(MOVE_OVERHEAD.default)
+        );
+        assert!(engine.network.is_none());
+    }
+}